@@ -0,0 +1,52 @@
+//! Generate a test for every input listed in `answers.toml`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/parse.rs"]
+mod parse;
+
+#[allow(dead_code)]
+#[path = "src/answers.rs"]
+mod answers;
+
+fn test_name(input: &str) -> String {
+    let name = input.strip_suffix(".txt").unwrap_or(input);
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", answers::FILE);
+    println!("cargo:rerun-if-changed=src/answers.rs");
+    println!("cargo:rerun-if-changed=src/parse.rs");
+
+    let manifest = fs::read_to_string(answers::FILE).unwrap();
+    let entries = match answers::parse(&manifest) {
+        Ok(entries) => entries,
+        Err(e) => panic!("{}", e.with_file(answers::FILE)),
+    };
+
+    let mut tests = String::new();
+    for entry in &entries {
+        if entry.slow {
+            writeln!(tests, "#[ignore = \"slow\"]").unwrap();
+        }
+        writeln!(tests, "#[test]").unwrap();
+        writeln!(tests, "fn {}() {{", test_name(&entry.input)).unwrap();
+        writeln!(
+            tests,
+            "    check({}, {:?}, {:?}, {:?});",
+            entry.day, entry.input, entry.part1, entry.part2
+        )
+        .unwrap();
+        writeln!(tests, "}}").unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("answers.rs"), tests).unwrap();
+}
@@ -0,0 +1,360 @@
+//! Run every registered input on a pool of threads and check the answers.
+//!
+//! A solver running longer than the timeout cannot be stopped, so its
+//! worker thread is abandoned and replaced by a new one. Abandoned threads
+//! die when the process exits.
+
+use std::fmt;
+use std::fmt::Write;
+use std::fs;
+use std::panic;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::answers::Expected;
+use crate::days;
+use crate::days::Part;
+use crate::debug;
+use crate::output;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub day: u32,
+    pub part: Part,
+    pub input: String,
+    pub expected: String,
+}
+
+/// One job per part with known answer.
+pub fn jobs(entries: &[Expected]) -> Vec<Job> {
+    let mut jobs = Vec::new();
+    for entry in entries {
+        for (part, expected) in [(Part::One, &entry.part1), (Part::Two, &entry.part2)] {
+            if let Some(expected) = expected {
+                jobs.push(Job {
+                    day: entry.day,
+                    part,
+                    input: entry.input.clone(),
+                    expected: expected.clone(),
+                });
+            }
+        }
+    }
+    jobs
+}
+
+/// Read the job input and solve it.
+pub fn solve(job: &Job) -> Result<String, String> {
+    let input = fs::read_to_string(&job.input)
+        .map_err(|e| format!("failed to read {}: {}", job.input, e))?;
+    days::run(job.day, job.part, &input).map_err(|e| e.with_file(&job.input).to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    /// Wrong answer or error.
+    Fail(String),
+    Timeout,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail(_) => write!(f, "FAIL"),
+            Status::Timeout => write!(f, "TIMEOUT"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub job: Job,
+    pub status: Status,
+    pub duration: Duration,
+}
+
+enum Event {
+    Started(usize),
+    Finished(usize, Result<String, String>, Duration),
+}
+
+type Solve = dyn Fn(&Job) -> Result<String, String> + Send + Sync;
+
+fn spawn_worker(
+    jobs: Arc<Mutex<mpsc::Receiver<(usize, Job)>>>,
+    events: mpsc::Sender<Event>,
+    solve: Arc<Solve>,
+) {
+    thread::spawn(move || loop {
+        // Lock is released before solving.
+        let (i, job) = match jobs.lock().unwrap().recv() {
+            Ok(job) => job,
+            Err(_) => return,
+        };
+        if events.send(Event::Started(i)).is_err() {
+            return;
+        }
+        let start = Instant::now();
+        // A panicked job is reported and the worker moves on.
+        let answer = match panic::catch_unwind(panic::AssertUnwindSafe(|| solve(&job))) {
+            Ok(answer) => answer,
+            Err(payload) => Err(output::panic_message(&*payload)),
+        };
+        if events
+            .send(Event::Finished(i, answer, start.elapsed()))
+            .is_err()
+        {
+            return;
+        }
+    });
+}
+
+/// Run `jobs` with `solve` on `workers` threads, return outcomes in the
+/// order of jobs.
+pub fn run_all(
+    jobs: Vec<Job>,
+    workers: usize,
+    timeout: Duration,
+    solve: impl Fn(&Job) -> Result<String, String> + Send + Sync + 'static,
+) -> Vec<Outcome> {
+    assert!(workers > 0);
+    let solve: Arc<Solve> = Arc::new(solve);
+    let (job_tx, job_rx) = mpsc::channel();
+    for (i, job) in jobs.iter().enumerate() {
+        job_tx.send((i, job.clone())).unwrap();
+    }
+    drop(job_tx);
+    let job_rx = Arc::new(Mutex::new(job_rx));
+    let (event_tx, event_rx) = mpsc::channel();
+    for _ in 0..workers.min(jobs.len()) {
+        spawn_worker(job_rx.clone(), event_tx.clone(), solve.clone());
+    }
+
+    let mut started: Vec<Option<Instant>> = vec![None; jobs.len()];
+    let mut statuses: Vec<Option<(Status, Duration)>> = vec![None; jobs.len()];
+    let mut done = 0;
+    while done != jobs.len() {
+        let deadline = started
+            .iter()
+            .zip(&statuses)
+            .filter(|(_, status)| status.is_none())
+            .filter_map(|(start, _)| start.map(|s| s + timeout))
+            .min();
+        let event = match deadline {
+            Some(deadline) => {
+                let wait = deadline.saturating_duration_since(Instant::now());
+                match event_rx.recv_timeout(wait) {
+                    Ok(event) => Some(event),
+                    Err(mpsc::RecvTimeoutError::Timeout) => None,
+                    Err(mpsc::RecvTimeoutError::Disconnected) => unreachable!(),
+                }
+            }
+            None => Some(event_rx.recv().unwrap()),
+        };
+
+        match event {
+            Some(Event::Started(i)) => started[i] = Some(Instant::now()),
+            Some(Event::Finished(i, answer, duration)) => {
+                if statuses[i].is_some() {
+                    // Already timed out.
+                    continue;
+                }
+                let job = &jobs[i];
+                let status = match answer {
+                    Ok(answer) if answer == job.expected => Status::Pass,
+                    Ok(answer) => {
+                        Status::Fail(format!("expected {:?}, got {:?}", job.expected, answer))
+                    }
+                    Err(e) => Status::Fail(e),
+                };
+                debug!(
+                    "{} day {} part {}: {}",
+                    job.input, job.day, job.part, status
+                );
+                statuses[i] = Some((status, duration));
+                done += 1;
+            }
+            None => {
+                let now = Instant::now();
+                for i in 0..jobs.len() {
+                    let expired = matches!(started[i], Some(s) if now >= s + timeout);
+                    if expired && statuses[i].is_none() {
+                        let job = &jobs[i];
+                        debug!("{} day {} part {}: TIMEOUT", job.input, job.day, job.part);
+                        statuses[i] = Some((Status::Timeout, timeout));
+                        done += 1;
+                        spawn_worker(job_rx.clone(), event_tx.clone(), solve.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    jobs.into_iter()
+        .zip(statuses)
+        .map(|(job, status)| {
+            let (status, duration) = status.unwrap();
+            Outcome {
+                job,
+                status,
+                duration,
+            }
+        })
+        .collect()
+}
+
+/// Number of passed, failed and timed out jobs.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub pass: usize,
+    pub fail: usize,
+    pub timeout: usize,
+}
+
+impl Counts {
+    pub fn new(outcomes: &[Outcome]) -> Counts {
+        let mut counts = Counts::default();
+        for outcome in outcomes {
+            match outcome.status {
+                Status::Pass => counts.pass += 1,
+                Status::Fail(_) => counts.fail += 1,
+                Status::Timeout => counts.timeout += 1,
+            }
+        }
+        counts
+    }
+}
+
+/// Table of outcomes followed by totals.
+pub fn report(outcomes: &[Outcome]) -> String {
+    let width = outcomes
+        .iter()
+        .map(|o| o.job.input.len())
+        .chain(["input".len()])
+        .max()
+        .unwrap();
+    let mut table = String::new();
+    writeln!(
+        table,
+        "{:>3}  {:>4}  {:<width$}  {:<7}  {:>10}",
+        "day", "part", "input", "status", "time"
+    )
+    .unwrap();
+    for outcome in outcomes {
+        write!(
+            table,
+            "{:>3}  {:>4}  {:<width$}  {:<7}  {:>10}",
+            outcome.job.day,
+            outcome.job.part,
+            outcome.job.input,
+            outcome.status,
+            format!("{:.2?}", outcome.duration),
+        )
+        .unwrap();
+        if let Status::Fail(reason) = &outcome.status {
+            write!(table, "  {}", reason).unwrap();
+        }
+        writeln!(table).unwrap();
+    }
+    let counts = Counts::new(outcomes);
+    writeln!(
+        table,
+        "{} passed, {} failed, {} timed out",
+        counts.pass, counts.fail, counts.timeout
+    )
+    .unwrap();
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inputs;
+
+    fn job(day: u32, expected: &str) -> Job {
+        Job {
+            day,
+            part: Part::One,
+            input: inputs::puzzle(day),
+            expected: expected.to_owned(),
+        }
+    }
+
+    #[test]
+    fn jobs_from_answers() {
+        let entries = [Expected {
+            input: "inputs/day25/input.txt".to_owned(),
+            day: 25,
+            part1: Some("1".to_owned()),
+            part2: None,
+            slow: false,
+        }];
+        assert_eq!(
+            vec![Job {
+                day: 25,
+                part: Part::One,
+                input: "inputs/day25/input.txt".to_owned(),
+                expected: "1".to_owned(),
+            }],
+            jobs(&entries)
+        );
+    }
+
+    #[test]
+    fn statuses() {
+        let jobs = vec![
+            job(1, "1"),
+            job(2, "3"),
+            job(3, "x"),
+            job(4, "4"),
+            job(5, "5"),
+        ];
+        let outcomes = run_all(jobs, 2, Duration::from_millis(200), |job| match job.day {
+            2 => Err("bad input".to_owned()),
+            3 => panic!("no win"),
+            // Never finishes in time, and must not block the following jobs.
+            4 => {
+                thread::sleep(Duration::from_secs(10));
+                Ok("4".to_owned())
+            }
+            day => Ok(day.to_string()),
+        });
+        let statuses: Vec<_> = outcomes.iter().map(|o| o.status.clone()).collect();
+        assert_eq!(
+            vec![
+                Status::Pass,
+                Status::Fail("bad input".to_owned()),
+                Status::Fail("panicked: no win".to_owned()),
+                Status::Timeout,
+                Status::Pass,
+            ],
+            statuses
+        );
+        assert_eq!(
+            Counts {
+                pass: 2,
+                fail: 2,
+                timeout: 1
+            },
+            Counts::new(&outcomes)
+        );
+        assert!(report(&outcomes).ends_with("2 passed, 2 failed, 1 timed out\n"));
+    }
+
+    #[test]
+    fn wrong_answer() {
+        let outcomes = run_all(vec![job(1, "2")], 1, Duration::from_secs(10), |_| {
+            Ok("1".to_owned())
+        });
+        assert_eq!(
+            Status::Fail("expected \"2\", got \"1\"".to_owned()),
+            outcomes[0].status
+        );
+    }
+}
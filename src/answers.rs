@@ -0,0 +1,246 @@
+//! Manifest of expected answers, `answers.toml`.
+//!
+//! The manifest is a small subset of TOML: one table per input file,
+//! keyed by the file path.
+//!
+//! ```toml
+//! ["inputs/day01/input.txt"]
+//! day = 1
+//! part1 = 1532
+//! part2 = 1571
+//! ```
+//!
+//! Answers are integers or strings, and a part without known answer is
+//! omitted. `slow = true` marks inputs too slow to check in debug build.
+
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+
+pub const FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub input: String,
+    pub day: u32,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub slow: bool,
+}
+
+enum Value<'a> {
+    Int(&'a str),
+    Bool(bool),
+    Str(String),
+}
+
+struct Table<'a> {
+    header: Line<'a>,
+    input: String,
+    day: Option<u32>,
+    part1: Option<String>,
+    part2: Option<String>,
+    slow: Option<bool>,
+}
+
+impl<'a> Table<'a> {
+    fn finish(self) -> Result<Expected, ParseError> {
+        let day = match self.day {
+            Some(day) => day,
+            None => return Err(self.header.error_at_end("`day` key in the table")),
+        };
+        Ok(Expected {
+            input: self.input,
+            day,
+            part1: self.part1,
+            part2: self.part2,
+            slow: self.slow.unwrap_or(false),
+        })
+    }
+
+    fn set(&mut self, line: &Line<'a>, key: &'a str, value: &'a str) -> Result<(), ParseError> {
+        let (parsed, rest) = parse_value(line, value)?;
+        end_of_line(line, rest)?;
+        let duplicate = match (key, parsed) {
+            ("day", Value::Int(s)) => self.day.replace(line.parse(s, "day number")?).is_some(),
+            ("part1", Value::Int(s)) => self.part1.replace(s.to_owned()).is_some(),
+            ("part1", Value::Str(s)) => self.part1.replace(s).is_some(),
+            ("part2", Value::Int(s)) => self.part2.replace(s.to_owned()).is_some(),
+            ("part2", Value::Str(s)) => self.part2.replace(s).is_some(),
+            ("slow", Value::Bool(b)) => self.slow.replace(b).is_some(),
+            ("day", _) => return Err(line.error(value, "integer")),
+            ("part1" | "part2", _) => return Err(line.error(value, "integer or string")),
+            ("slow", _) => return Err(line.error(value, "`true` or `false`")),
+            _ => {
+                return Err(line.error(key, "`day`, `part1`, `part2` or `slow`"));
+            }
+        };
+        if duplicate {
+            return Err(line.error(key, "key not set before"));
+        }
+        Ok(())
+    }
+}
+
+/// Parse basic string starting at `s`, return it and the rest after it.
+fn parse_string<'a>(line: &Line<'a>, s: &'a str) -> Result<(String, &'a str), ParseError> {
+    let body = line.strip_prefix(s, "\"")?;
+    let mut result = String::new();
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((result, &body[i + 1..])),
+            '\\' => match chars.next() {
+                Some((_, '"')) => result.push('"'),
+                Some((_, '\\')) => result.push('\\'),
+                Some((_, 'n')) => result.push('\n'),
+                Some((_, 't')) => result.push('\t'),
+                _ => return Err(line.error(&body[i..], "escape sequence")),
+            },
+            c => result.push(c),
+        }
+    }
+    Err(line.error_at_end("`\"`"))
+}
+
+fn parse_value<'a>(line: &Line<'a>, s: &'a str) -> Result<(Value<'a>, &'a str), ParseError> {
+    if s.starts_with('"') {
+        let (string, rest) = parse_string(line, s)?;
+        return Ok((Value::Str(string), rest));
+    }
+    let end = s
+        .find(|c: char| c.is_whitespace() || c == '#')
+        .unwrap_or(s.len());
+    let (token, rest) = s.split_at(end);
+    let value = match token {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => {
+            let digits = token.strip_prefix('-').unwrap_or(token);
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(line.error(s, "value"));
+            }
+            Value::Int(token)
+        }
+    };
+    Ok((value, rest))
+}
+
+fn end_of_line(line: &Line, rest: &str) -> Result<(), ParseError> {
+    let rest = rest.trim_start();
+    if rest.is_empty() || rest.starts_with('#') {
+        Ok(())
+    } else {
+        Err(line.error(rest, "end of line"))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Expected>, ParseError> {
+    let mut entries: Vec<Expected> = Vec::new();
+    let mut table: Option<Table> = None;
+    for line in parse::lines(input) {
+        let text = line.text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+
+        if let Some(rest) = text.strip_prefix('[') {
+            if let Some(table) = table.take() {
+                entries.push(table.finish()?);
+            }
+            let (input, rest) = parse_string(&line, rest)?;
+            end_of_line(&line, line.strip_prefix(rest, "]")?)?;
+            if entries.iter().any(|e| e.input == input) {
+                return Err(line.error(text, "input file not listed before"));
+            }
+            table = Some(Table {
+                header: line,
+                input,
+                day: None,
+                part1: None,
+                part2: None,
+                slow: None,
+            });
+            continue;
+        }
+
+        let table = match &mut table {
+            Some(table) => table,
+            None => return Err(line.error(text, "table header")),
+        };
+        let (key, value) = line.split_once(text, "=")?;
+        table.set(&line, key.trim_end(), value.trim_start())?;
+    }
+    if let Some(table) = table {
+        entries.push(table.finish()?);
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tables() {
+        let entries = parse(
+            "# comment\n\
+             [\"inputs/day01/input.txt\"]\n\
+             day = 1\n\
+             part1 = 1532 # trailing comment\n\
+             \n\
+             [\"inputs/day13/example.txt\"]\n\
+             day = 13\n\
+             part2 = \"\\n#.#\"\n\
+             slow = true\n",
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                Expected {
+                    input: "inputs/day01/input.txt".to_owned(),
+                    day: 1,
+                    part1: Some("1532".to_owned()),
+                    part2: None,
+                    slow: false,
+                },
+                Expected {
+                    input: "inputs/day13/example.txt".to_owned(),
+                    day: 13,
+                    part1: None,
+                    part2: Some("\n#.#".to_owned()),
+                    slow: true,
+                },
+            ],
+            entries
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            ParseError::new(1, 1, "table header"),
+            parse("day = 1").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(1, 10, "`day` key in the table"),
+            parse("[\"a.txt\"]\npart1 = 1").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(3, 1, "key not set before"),
+            parse("[\"a.txt\"]\nday = 1\nday = 2").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(2, 7, "integer"),
+            parse("[\"a.txt\"]\nday = \"1\"").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(2, 11, "`\"`"),
+            parse("[\"a.txt\"]\npart1 = \"x").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(2, 1, "`day`, `part1`, `part2` or `slow`"),
+            parse("[\"a.txt\"]\npart3 = 1").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Timing of parse, part 1 and part 2 over several iterations.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Write;
+use std::hint;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::days;
+use crate::days::SolutionFn;
+use crate::parse;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty());
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct DayBench {
+    pub day: u32,
+    pub stages: Vec<(Stage, Stats)>,
+}
+
+/// Run `f` `iterations` times, return the last result and timings.
+fn time<T>(iterations: u32, mut f: impl FnMut() -> Result<T, Error>) -> Result<(T, Stats), Error> {
+    assert!(iterations > 0);
+    let mut samples = Vec::new();
+    let mut last = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let r = hint::black_box(f()?);
+        samples.push(start.elapsed());
+        last = Some(r);
+    }
+    Ok((last.unwrap(), Stats::new(samples)))
+}
+
+struct Bench<'a> {
+    input: &'a str,
+    iterations: u32,
+}
+
+impl<'a> SolutionFn for Bench<'a> {
+    type Output = Result<Vec<(Stage, Stats)>, Error>;
+
+    fn call<S: Solution>(self) -> Result<Vec<(Stage, Stats)>, Error> {
+        let (input, parse) = time(self.iterations, || S::parse(self.input))?;
+        let (_, part1) = time(self.iterations, || S::part1(&input))?;
+        let mut stages = vec![(Stage::Parse, parse), (Stage::Part1, part1)];
+        match time(self.iterations, || S::part2(&input)) {
+            Ok((_, part2)) => stages.push((Stage::Part2, part2)),
+            Err(Error::NoSuchPart { .. }) => {}
+            Err(e) => return Err(e),
+        }
+        Ok(stages)
+    }
+}
+
+pub fn bench(day: u32, input: &str, iterations: u32) -> Result<DayBench, Error> {
+    let stages = days::with_solution(day, Bench { input, iterations })??;
+    Ok(DayBench { day, stages })
+}
+
+/// Median timings saved from a previous run.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u32, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn new(results: &[DayBench]) -> Baseline {
+        let mut medians = BTreeMap::new();
+        for result in results {
+            for (stage, stats) in &result.stages {
+                medians.insert((result.day, *stage), stats.median);
+            }
+        }
+        Baseline { medians }
+    }
+
+    /// One `day stage nanoseconds` triple per line.
+    pub fn parse(input: &str) -> Result<Baseline, ParseError> {
+        let mut medians = BTreeMap::new();
+        for line in parse::lines(input) {
+            if line.text.is_empty() || line.text.starts_with('#') {
+                continue;
+            }
+            let (day, rest) = line.split_once(line.text, " ")?;
+            let (stage_s, nanos) = line.split_once(rest, " ")?;
+            let day = line.parse(day, "day")?;
+            let stage = match Stage::ALL.iter().find(|s| s.name() == stage_s) {
+                Some(stage) => *stage,
+                None => return Err(line.error(stage_s, "`parse`, `part1` or `part2`")),
+            };
+            let nanos = line.parse(nanos, "nanoseconds")?;
+            medians.insert((day, stage), Duration::from_nanos(nanos));
+        }
+        Ok(Baseline { medians })
+    }
+
+    pub fn get(&self, day: u32, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# day stage median_ns")?;
+        for ((day, stage), median) in &self.medians {
+            writeln!(f, "{} {} {}", day, stage, median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+/// Change of `current` relative to `baseline`, in percent.
+fn change_percent(baseline: Duration, current: Duration) -> f64 {
+    (current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+}
+
+/// Format results as a table, comparing medians against the baseline.
+/// Return the table and the number of medians slower than baseline by more
+/// than `threshold` percent.
+pub fn report(
+    results: &[DayBench],
+    baseline: Option<&Baseline>,
+    threshold: f64,
+) -> (String, usize) {
+    let mut table = String::new();
+    let mut regressions = 0;
+    write!(
+        table,
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+        "day", "stage", "min", "median", "max"
+    )
+    .unwrap();
+    if baseline.is_some() {
+        write!(table, "  {:>10}  {:>7}", "baseline", "change").unwrap();
+    }
+    writeln!(table).unwrap();
+    for result in results {
+        for (stage, stats) in &result.stages {
+            write!(
+                table,
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+                result.day,
+                stage,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+            )
+            .unwrap();
+            if let Some(old) = baseline.and_then(|b| b.get(result.day, *stage)) {
+                let change = change_percent(old, stats.median);
+                write!(table, "  {:>10}  {:>+6.1}%", format!("{:.2?}", old), change).unwrap();
+                if change > threshold {
+                    regressions += 1;
+                    write!(table, "  REGRESSION").unwrap();
+                }
+            }
+            writeln!(table).unwrap();
+        }
+    }
+    (table, regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    fn day_bench(day: u32, medians: &[u64]) -> DayBench {
+        let stages = Stage::ALL
+            .iter()
+            .zip(medians)
+            .map(|(stage, median)| {
+                let median = ms(*median);
+                let stats = Stats {
+                    min: median,
+                    median,
+                    max: median,
+                };
+                (*stage, stats)
+            })
+            .collect();
+        DayBench { day, stages }
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!((ms(1), ms(3), ms(5)), (stats.min, stats.median, stats.max));
+        let stats = Stats::new(vec![ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(ms(3), stats.median);
+    }
+
+    #[test]
+    fn baseline_roundtrip() {
+        let baseline = Baseline::new(&[day_bench(1, &[1, 2, 3]), day_bench(25, &[4, 5])]);
+        assert_eq!(Some(ms(3)), baseline.get(1, Stage::Part2));
+        assert_eq!(None, baseline.get(25, Stage::Part2));
+        assert_eq!(baseline, Baseline::parse(&baseline.to_string()).unwrap());
+    }
+
+    #[test]
+    fn baseline_parse_error() {
+        assert_eq!(
+            ParseError::new(2, 3, "`parse`, `part1` or `part2`"),
+            Baseline::parse("1 parse 10\n1 part3 10\n").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn report_regressions() {
+        let baseline = Baseline::new(&[day_bench(1, &[10, 10, 10])]);
+        let (table, regressions) = report(&[day_bench(1, &[10, 11, 20])], Some(&baseline), 10.0);
+        assert_eq!(1, regressions);
+        assert_eq!(1, table.matches("REGRESSION").count());
+        assert!(table.lines().nth(3).unwrap().ends_with("REGRESSION"));
+    }
+}
@@ -0,0 +1,238 @@
+//! Arithmetic which reports overflow as an error in checked mode.
+//!
+//! Solvers use fixed-width integers sized for the puzzle inputs. With
+//! `--checked` in the runner every operation done through this module
+//! returns `Overflow` instead of wrapping (or panicking in debug build).
+//! Without it the operations are plain operators.
+//!
+//! Solvers go through this module for values which grow with the input:
+//! sums, products, counts and scores, and for the day 24 ALU running a
+//! program. Arithmetic bounded by the puzzle geometry, like positions and
+//! costs of single moves, uses plain operators, and so does the day 24
+//! search specialized to the blocks of the puzzle program.
+
+#[cfg(test)]
+use std::cell::Cell;
+use std::fmt;
+use std::ops::Add;
+use std::ops::Mul;
+use std::ops::Shl;
+use std::ops::Sub;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+static CHECKED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_enabled(enabled: bool) {
+    CHECKED.store(enabled, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Mode of the current test thread, so that tests running in parallel
+    /// do not change the mode of each other.
+    static TEST_MODE: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Override the mode of the current test thread, `None` for the global
+/// mode.
+#[cfg(test)]
+pub fn set_test_mode(mode: Option<bool>) {
+    TEST_MODE.set(mode);
+}
+
+pub fn enabled() -> bool {
+    #[cfg(test)]
+    if let Some(enabled) = TEST_MODE.get() {
+        return enabled;
+    }
+    CHECKED.load(Ordering::Relaxed)
+}
+
+/// Operation which did not fit the type, like `4294967295 + 1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub op: &'static str,
+    pub lhs: String,
+    pub rhs: String,
+}
+
+impl Overflow {
+    fn new(op: &'static str, lhs: impl fmt::Display, rhs: impl fmt::Display) -> Overflow {
+        Overflow {
+            op,
+            lhs: lhs.to_string(),
+            rhs: rhs.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "arithmetic overflow: {} {} {}",
+            self.lhs, self.op, self.rhs
+        )
+    }
+}
+
+pub trait Int:
+    Copy
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Shl<u32, Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Shift which does not lose set bits.
+    fn checked_shl_bits(self, bits: u32) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const ZERO: $t = 0;
+                const ONE: $t = 1;
+
+                fn checked_add(self, rhs: $t) -> Option<$t> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: $t) -> Option<$t> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: $t) -> Option<$t> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_shl_bits(self, bits: u32) -> Option<$t> {
+                    let r = <$t>::checked_shl(self, bits)?;
+                    if r >> bits == self {
+                        Some(r)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(u8, u16, u32, u64, u128, usize, i32, i64, i128, isize);
+
+pub fn add<T: Int>(a: T, b: T) -> Result<T, Overflow> {
+    if !enabled() {
+        return Ok(a + b);
+    }
+    a.checked_add(b).ok_or_else(|| Overflow::new("+", a, b))
+}
+
+pub fn sub<T: Int>(a: T, b: T) -> Result<T, Overflow> {
+    if !enabled() {
+        return Ok(a - b);
+    }
+    a.checked_sub(b).ok_or_else(|| Overflow::new("-", a, b))
+}
+
+pub fn mul<T: Int>(a: T, b: T) -> Result<T, Overflow> {
+    if !enabled() {
+        return Ok(a * b);
+    }
+    a.checked_mul(b).ok_or_else(|| Overflow::new("*", a, b))
+}
+
+pub fn shl<T: Int>(a: T, bits: u32) -> Result<T, Overflow> {
+    if !enabled() {
+        return Ok(a << bits);
+    }
+    a.checked_shl_bits(bits)
+        .ok_or_else(|| Overflow::new("<<", a, bits))
+}
+
+pub fn sum<T: Int>(values: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::ZERO, add)
+}
+
+pub fn product<T: Int>(values: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::ONE, mul)
+}
+
+/// Conversion between integer types, like `as` when not checked.
+pub fn convert<T, U>(v: T) -> Result<U, Overflow>
+where
+    T: Copy + fmt::Display + TryInto<U> + AsPrimitive<U>,
+{
+    if !enabled() {
+        return Ok(v.as_());
+    }
+    v.try_into()
+        .map_err(|_| Overflow::new("as", v, std::any::type_name::<U>()))
+}
+
+/// `as` conversion, for `convert` in unchecked mode.
+pub trait AsPrimitive<U> {
+    fn as_(self) -> U;
+}
+
+macro_rules! impl_as {
+    ($($f:ty),* => $($t:ty),*) => {
+        impl_as!(@each [$($f),*] [$($t),*]);
+    };
+    (@each [$($f:ty),*] $ts:tt) => {
+        $(impl_as!(@one $f $ts);)*
+    };
+    (@one $f:ty [$($t:ty),*]) => {
+        $(
+            impl AsPrimitive<$t> for $f {
+                fn as_(self) -> $t {
+                    self as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_as!(u8, u16, u32, u64, u128, usize, i32, i64, i128, isize
+    => u8, u16, u32, u64, u128, usize, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflow() {
+        set_test_mode(Some(true));
+        assert_eq!(Ok(3u32), add(1, 2));
+        assert_eq!(Err(Overflow::new("+", u32::MAX, 1)), add(u32::MAX, 1u32));
+        assert_eq!(
+            "arithmetic overflow: 1 - 2",
+            sub(1u32, 2).unwrap_err().to_string()
+        );
+        assert_eq!(
+            Err(Overflow::new("*", 1u64 << 32, 1u64 << 32)),
+            mul(1u64 << 32, 1 << 32)
+        );
+        assert_eq!(Ok(0b1000u8), shl(1u8, 3));
+        assert_eq!(
+            Err(Overflow::new("<<", 0b1000_0000u8, 1)),
+            shl(0b1000_0000u8, 1)
+        );
+        assert_eq!(Ok(6u64), product([1, 2, 3]));
+        assert!(sum([u64::MAX, 1]).is_err());
+        assert_eq!(Ok(5u32), convert(5u64));
+        assert_eq!(
+            "arithmetic overflow: 4294967296 as u32",
+            convert::<u64, u32>(1 << 32).unwrap_err().to_string()
+        );
+        set_test_mode(None);
+    }
+}
@@ -0,0 +1,392 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::BufRead;
+use std::num::NonZeroUsize;
+
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
+
+fn parse_depths(input: &str) -> Result<Vec<u32>, ParseError> {
+    parse::lines(input)
+        .map(|line| line.parse(line.text, "depth"))
+        .collect()
+}
+
+/// Sum of a window compared to the sum of the previous window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increase,
+    Decrease,
+    Equal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    /// Index of the window, which is also the index of its first depth.
+    pub window: usize,
+    pub trend: Trend,
+}
+
+/// Windows `start..=end`, each with larger sum than the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Run {
+    pub fn increases(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub increases: usize,
+    pub decreases: usize,
+    pub equal: usize,
+    /// The first of the longest runs, `None` if sums never increase.
+    pub longest_run: Option<Run>,
+}
+
+/// Compares sliding window sums of depths as they come, keeping only the
+/// last window in memory.
+pub struct Analyzer {
+    size: usize,
+    window: VecDeque<u32>,
+    /// Number of complete windows seen.
+    windows: usize,
+    run_start: Option<usize>,
+    report: Report,
+}
+
+impl Analyzer {
+    pub fn new(size: NonZeroUsize) -> Analyzer {
+        let size = size.get();
+        Analyzer {
+            size,
+            window: VecDeque::with_capacity(size),
+            windows: 0,
+            run_start: None,
+            report: Report::default(),
+        }
+    }
+
+    /// Add the next depth, return how the new window compares to the
+    /// previous one, `None` if there is no previous window yet.
+    pub fn push(&mut self, depth: u32) -> Option<Change> {
+        if self.window.len() < self.size {
+            self.window.push_back(depth);
+            if self.window.len() == self.size {
+                self.windows = 1;
+            }
+            return None;
+        }
+
+        // Windows share all depths except the first of the previous one
+        // and the last of the new one.
+        let first = self.window.pop_front().unwrap();
+        self.window.push_back(depth);
+        let window = self.windows;
+        self.windows += 1;
+
+        let trend = match depth.cmp(&first) {
+            Ordering::Greater => Trend::Increase,
+            Ordering::Less => Trend::Decrease,
+            Ordering::Equal => Trend::Equal,
+        };
+        match trend {
+            Trend::Increase => self.report.increases += 1,
+            Trend::Decrease => self.report.decreases += 1,
+            Trend::Equal => self.report.equal += 1,
+        }
+        if trend == Trend::Increase {
+            let run = Run {
+                start: *self.run_start.get_or_insert(window - 1),
+                end: window,
+            };
+            let longest = self.report.longest_run;
+            if longest.is_none_or(|r| run.increases() > r.increases()) {
+                self.report.longest_run = Some(run);
+            }
+        } else {
+            self.run_start = None;
+        }
+        Some(Change { window, trend })
+    }
+
+    pub fn report(&self) -> &Report {
+        &self.report
+    }
+}
+
+/// Analyze depths, one per line of `reader`, calling `on_change` for every
+/// window after the first.
+pub fn analyze(
+    mut reader: impl BufRead,
+    size: NonZeroUsize,
+    mut on_change: impl FnMut(Change),
+) -> Result<Report, Error> {
+    let mut analyzer = Analyzer::new(size);
+    let mut text = String::new();
+    let mut number = 0;
+    loop {
+        text.clear();
+        if reader.read_line(&mut text)? == 0 {
+            return Ok(analyzer.report().clone());
+        }
+        number += 1;
+        let line = Line {
+            text: text.trim_end_matches(['\n', '\r']),
+            number,
+        };
+        if let Some(change) = analyzer.push(line.parse(line.text, "depth")?) {
+            on_change(change);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnomalyConfig {
+    /// Number of previous samples the median is taken over.
+    pub window: NonZeroUsize,
+    /// Allowed deviation from the median in MADs.
+    pub k: f64,
+    /// Largest allowed difference between consecutive samples.
+    pub jump: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnomalyKind {
+    /// Too far from the rolling median.
+    Outlier,
+    /// Too far from the previous sample.
+    Jump,
+}
+
+/// Sample outside of `low..=high`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    pub index: usize,
+    pub value: u32,
+    pub kind: AnomalyKind,
+    pub low: f64,
+    pub high: f64,
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self.kind {
+            AnomalyKind::Outlier => "outlier",
+            AnomalyKind::Jump => "jump",
+        };
+        write!(
+            f,
+            "{}: {} {}, expected {}..={}",
+            self.index, kind, self.value, self.low, self.high
+        )
+    }
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    }
+}
+
+/// Median and median absolute deviation.
+fn median_mad(values: impl Iterator<Item = u32>) -> (f64, f64) {
+    let mut values: Vec<f64> = values.map(f64::from).collect();
+    values.sort_by(f64::total_cmp);
+    let median = median(&values);
+    let mut deviations: Vec<f64> = values.iter().map(|v| (v - median).abs()).collect();
+    deviations.sort_by(f64::total_cmp);
+    (median, self::median(&deviations))
+}
+
+/// Finds anomalies in depths as they come, keeping only the last window.
+pub struct Detector {
+    config: AnomalyConfig,
+    /// Previous samples, the oldest first.
+    window: VecDeque<u32>,
+    index: usize,
+}
+
+impl Detector {
+    pub fn new(config: AnomalyConfig) -> Detector {
+        Detector {
+            config,
+            window: VecDeque::with_capacity(config.window.get()),
+            index: 0,
+        }
+    }
+
+    /// Add the next depth, return its anomalies. Outliers are only detected
+    /// once there are `window` previous samples. Outliers stay in the
+    /// window, the median is robust to them.
+    pub fn push(&mut self, value: u32) -> Vec<Anomaly> {
+        let mut anomalies = Vec::new();
+        let anomaly = |kind, low, high| Anomaly {
+            index: self.index,
+            value,
+            kind,
+            low,
+            high,
+        };
+        if self.window.len() == self.config.window.get() {
+            let (median, mad) = median_mad(self.window.iter().copied());
+            let (low, high) = (median - self.config.k * mad, median + self.config.k * mad);
+            if !(low..=high).contains(&f64::from(value)) {
+                anomalies.push(anomaly(AnomalyKind::Outlier, low, high));
+            }
+        }
+        if let Some(&prev) = self.window.back() {
+            if prev.abs_diff(value) > self.config.jump {
+                let prev = f64::from(prev);
+                let jump = f64::from(self.config.jump);
+                anomalies.push(anomaly(AnomalyKind::Jump, prev - jump, prev + jump));
+            }
+        }
+
+        if self.window.len() == self.config.window.get() {
+            self.window.pop_front();
+        }
+        self.window.push_back(value);
+        self.index += 1;
+        anomalies
+    }
+}
+
+/// Anomalies ordered by index.
+pub fn anomalies(depths: &[u32], config: AnomalyConfig) -> Vec<Anomaly> {
+    let mut detector = Detector::new(config);
+    depths.iter().flat_map(|&d| detector.push(d)).collect()
+}
+
+fn count_increases(depths: &[u32], size: NonZeroUsize) -> usize {
+    let mut analyzer = Analyzer::new(size);
+    for &depth in depths {
+        analyzer.push(depth);
+    }
+    analyzer.report().increases
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<u32>, Error> {
+        Ok(parse_depths(input)?)
+    }
+
+    fn part1(depths: &Vec<u32>) -> Result<usize, Error> {
+        Ok(count_increases(depths, NonZeroUsize::MIN))
+    }
+
+    fn part2(depths: &Vec<u32>) -> Result<usize, Error> {
+        Ok(count_increases(depths, NonZeroUsize::new(3).unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    fn size(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn single() {
+        let report = analyze(EXAMPLE.as_bytes(), size(1), |_| ()).unwrap();
+        assert_eq!(
+            Report {
+                increases: 7,
+                decreases: 2,
+                equal: 0,
+                longest_run: Some(Run { start: 0, end: 3 }),
+            },
+            report
+        );
+    }
+
+    #[test]
+    fn windows() {
+        let mut changes = Vec::new();
+        let report = analyze(EXAMPLE.as_bytes(), size(3), |c| changes.push(c)).unwrap();
+        assert_eq!(
+            Report {
+                increases: 5,
+                decreases: 1,
+                equal: 1,
+                longest_run: Some(Run { start: 3, end: 7 }),
+            },
+            report
+        );
+        let trends: Vec<_> = changes.iter().map(|c| (c.window, c.trend)).collect();
+        assert_eq!(
+            vec![
+                (1, Trend::Increase),
+                (2, Trend::Equal),
+                (3, Trend::Decrease),
+                (4, Trend::Increase),
+                (5, Trend::Increase),
+                (6, Trend::Increase),
+                (7, Trend::Increase),
+            ],
+            trends
+        );
+    }
+
+    #[test]
+    fn short() {
+        let report = analyze("1\n2\n".as_bytes(), size(3), |_| panic!()).unwrap();
+        assert_eq!(Report::default(), report);
+    }
+
+    #[test]
+    fn anomalies() {
+        let depths = [100, 101, 103, 102, 104, 150, 105, 106, 108, 107];
+        let config = AnomalyConfig {
+            window: size(4),
+            k: 3.0,
+            jump: 20,
+        };
+        let found = super::anomalies(&depths, config);
+        let summary: Vec<_> = found.iter().map(|a| (a.index, a.kind)).collect();
+        assert_eq!(
+            vec![
+                (5, AnomalyKind::Outlier),
+                (5, AnomalyKind::Jump),
+                (6, AnomalyKind::Jump),
+            ],
+            summary
+        );
+        // Median of 101, 103, 102, 104 is 102.5, MAD is 1.
+        assert_eq!(
+            "5: outlier 150, expected 99.5..=105.5",
+            found[0].to_string()
+        );
+        assert_eq!("6: jump 105, expected 130..=170", found[2].to_string());
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(
+            Error::Parse(ParseError::new(2, 1, "depth")),
+            analyze("1\nx\n".as_bytes(), size(1), |_| ()).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+
+use crate::checked;
+use crate::checked::Overflow;
+use crate::debug;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::Lines;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Up(i64),
+    Down(i64),
+    Forward(i64),
+    Backward(i64),
+}
+
+/// Script of commands, one per line, with extensions over the puzzle input:
+///
+/// ```text
+/// # Comment, also allowed after a command.
+/// macro dive {
+///     down 2
+///     forward 3
+/// }
+/// repeat 3 {
+///     dive
+///     backward 1
+/// }
+/// ```
+///
+/// Macros are defined at the top level before they are used.
+struct Script<'a> {
+    lines: Lines<'a>,
+    macros: HashMap<&'a str, Vec<Command>>,
+}
+
+impl<'a> Script<'a> {
+    /// Commands until the end of input, or until `}` closing the block
+    /// opened on `open`.
+    fn parse_block(&mut self, open: Option<&Line>) -> Result<Vec<Command>, ParseError> {
+        let mut commands = Vec::new();
+        loop {
+            let line = match self.lines.next() {
+                Some(line) => line,
+                None => {
+                    if let Some(open) = open {
+                        return Err(self.lines.error_after_last(&format!(
+                            "`}}` closing the block opened on line {}",
+                            open.number
+                        )));
+                    }
+                    return Ok(commands);
+                }
+            };
+            let text = match line.text.split_once('#') {
+                Some((text, _comment)) => text,
+                None => line.text,
+            };
+            let words: Vec<&str> = text.split_whitespace().collect();
+            match words[..] {
+                [] => {}
+                ["}"] if open.is_some() => return Ok(commands),
+                ["repeat", count_text, "{"] => {
+                    let count: usize = line.parse(count_text, "repeat count")?;
+                    let body = self.parse_block(Some(&line))?;
+                    let len = body
+                        .len()
+                        .checked_mul(count)
+                        .and_then(|n| n.checked_add(commands.len()));
+                    if len.is_none_or(|n| n > MAX_COMMANDS) {
+                        return Err(line.error(count_text, too_long()));
+                    }
+                    for _ in 0..count {
+                        commands.extend_from_slice(&body);
+                    }
+                }
+                ["macro", name, "{"] => {
+                    if open.is_some() {
+                        return Err(line.error(words[0], "command"));
+                    }
+                    if self.macros.contains_key(name) || is_keyword(name) {
+                        return Err(line.error(name, "new macro name"));
+                    }
+                    let body = self.parse_block(Some(&line))?;
+                    self.macros.insert(name, body);
+                }
+                [command @ ("up" | "down" | "forward" | "backward"), param] => {
+                    let param = line.parse::<u32>(param, "number")?.into();
+                    commands.push(match command {
+                        "up" => Command::Up(param),
+                        "down" => Command::Down(param),
+                        "forward" => Command::Forward(param),
+                        "backward" => Command::Backward(param),
+                        _ => unreachable!(),
+                    });
+                }
+                [name] if self.macros.contains_key(name) => {
+                    if commands.len() + self.macros[name].len() > MAX_COMMANDS {
+                        return Err(line.error(name, too_long()));
+                    }
+                    commands.extend_from_slice(&self.macros[name]);
+                }
+                [word, ..] if is_keyword(word) => return Err(line.error(word, usage(word))),
+                [word, ..] => return Err(line.error(word, "command or macro name")),
+            }
+        }
+    }
+}
+
+/// Largest number of commands a script may expand to.
+const MAX_COMMANDS: usize = 1 << 20;
+
+fn too_long() -> String {
+    format!("script of at most {} commands", MAX_COMMANDS)
+}
+
+fn is_keyword(word: &str) -> bool {
+    matches!(
+        word,
+        "up" | "down" | "forward" | "backward" | "repeat" | "macro"
+    )
+}
+
+/// Expected form of a line starting with a keyword.
+fn usage(keyword: &str) -> String {
+    match keyword {
+        "repeat" => "`repeat N {`".to_owned(),
+        "macro" => "`macro NAME {`".to_owned(),
+        _ => format!("`{} N`", keyword),
+    }
+}
+
+fn parse_commands(input: &str) -> Result<Vec<Command>, ParseError> {
+    let mut script = Script {
+        lines: parse::lines(input),
+        macros: HashMap::new(),
+    };
+    script.parse_block(None)
+}
+
+/// Depth grows downwards, negative depth is above the surface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    pub pos: i64,
+    pub depth: i64,
+    /// Always zero in models without aim.
+    pub aim: i64,
+}
+
+/// Interpretation of the commands.
+pub trait SteeringModel {
+    fn apply(&self, position: Position, command: Command) -> Result<Position, Overflow>;
+}
+
+/// Up and down change the depth.
+pub struct PlainModel;
+
+impl SteeringModel for PlainModel {
+    fn apply(&self, p: Position, command: Command) -> Result<Position, Overflow> {
+        Ok(match command {
+            Command::Up(n) => Position {
+                depth: checked::sub(p.depth, n)?,
+                ..p
+            },
+            Command::Down(n) => Position {
+                depth: checked::add(p.depth, n)?,
+                ..p
+            },
+            Command::Forward(n) => Position {
+                pos: checked::add(p.pos, n)?,
+                ..p
+            },
+            Command::Backward(n) => Position {
+                pos: checked::sub(p.pos, n)?,
+                ..p
+            },
+        })
+    }
+}
+
+/// Up and down change the aim, forward moves along it.
+pub struct AimModel;
+
+impl SteeringModel for AimModel {
+    fn apply(&self, p: Position, command: Command) -> Result<Position, Overflow> {
+        Ok(match command {
+            Command::Up(n) => Position {
+                aim: checked::sub(p.aim, n)?,
+                ..p
+            },
+            Command::Down(n) => Position {
+                aim: checked::add(p.aim, n)?,
+                ..p
+            },
+            Command::Forward(n) => Position {
+                pos: checked::add(p.pos, n)?,
+                depth: checked::add(p.depth, checked::mul(p.aim, n)?)?,
+                ..p
+            },
+            // Reverses along the aim, as forward with negative distance.
+            Command::Backward(n) => Position {
+                pos: checked::sub(p.pos, n)?,
+                depth: checked::sub(p.depth, checked::mul(p.aim, n)?)?,
+                ..p
+            },
+        })
+    }
+}
+
+pub struct Submarine<M: SteeringModel> {
+    model: M,
+    /// Start followed by the position after every command.
+    trajectory: Vec<Position>,
+}
+
+impl<M: SteeringModel> Submarine<M> {
+    pub fn new(model: M) -> Submarine<M> {
+        Submarine {
+            model,
+            trajectory: vec![Position::default()],
+        }
+    }
+
+    pub fn position(&self) -> Position {
+        *self.trajectory.last().unwrap()
+    }
+
+    pub fn trajectory(&self) -> &[Position] {
+        &self.trajectory
+    }
+
+    pub fn drive(&mut self, command: Command) -> Result<(), Overflow> {
+        let position = self.model.apply(self.position(), command)?;
+        if position.depth < 0 && self.position().depth >= 0 {
+            debug!(
+                "above the surface after command {}: {:?}",
+                self.trajectory.len(),
+                position
+            );
+        }
+        self.trajectory.push(position);
+        Ok(())
+    }
+
+    /// Indices of commands, starting from 1, after which the submarine is
+    /// above the surface.
+    pub fn above_surface(&self) -> Vec<usize> {
+        self.trajectory
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.depth < 0)
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+fn run(model: impl SteeringModel, commands: &[Command]) -> Result<i64, Error> {
+    let mut submarine = Submarine::new(model);
+    for &command in commands {
+        submarine.drive(command)?;
+    }
+    let end = submarine.position();
+    Ok(checked::mul(end.pos, end.depth)?)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Command>, Error> {
+        Ok(parse_commands(input)?)
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<i64, Error> {
+        run(PlainModel, commands)
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<i64, Error> {
+        run(AimModel, commands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn models() {
+        let commands = parse_commands(EXAMPLE).unwrap();
+        assert_eq!(150, run(PlainModel, &commands).unwrap());
+        assert_eq!(900, run(AimModel, &commands).unwrap());
+    }
+
+    #[test]
+    fn script() {
+        let script = "\
+# Comments and blank lines are skipped.
+
+macro dive {
+    down 2 # trailing comment
+    forward 3
+}
+repeat 2 {
+    dive
+    repeat 0 {
+        up 100
+    }
+    backward 1
+}
+";
+        use Command::*;
+        assert_eq!(
+            vec![
+                Down(2),
+                Forward(3),
+                Backward(1),
+                Down(2),
+                Forward(3),
+                Backward(1)
+            ],
+            parse_commands(script).unwrap()
+        );
+    }
+
+    #[test]
+    fn script_errors() {
+        let error = |script| parse_commands(script).unwrap_err();
+        assert_eq!(
+            ParseError::new(2, 1, "command or macro name"),
+            error("up 1\ndive\n")
+        );
+        assert_eq!(
+            ParseError::new(3, 1, "`}` closing the block opened on line 2"),
+            error("up 1\nrepeat 2 {\n")
+        );
+        assert_eq!(
+            ParseError::new(1, 8, format!("script of at most {} commands", MAX_COMMANDS)),
+            error("repeat 99999999999 {\nforward 1\n}\n")
+        );
+        assert_eq!(
+            ParseError::new(4, 8, format!("script of at most {} commands", MAX_COMMANDS)),
+            error("repeat 65536 {\nforward 1\n}\nrepeat 65536 {\nrepeat 16 {\nup 1\n}\n}\n")
+        );
+        assert_eq!(
+            ParseError::new(1, 8, "repeat count"),
+            error("repeat x {\n}\n")
+        );
+        assert_eq!(ParseError::new(1, 1, "`down N`"), error("down\n"));
+        assert_eq!(
+            ParseError::new(3, 7, "new macro name"),
+            error("macro a {\n}\nmacro a {\n}\n")
+        );
+    }
+
+    #[test]
+    fn trajectory() {
+        let mut submarine = Submarine::new(AimModel);
+        for command in parse_commands("up 2\nforward 3\ndown 4\nforward 1").unwrap() {
+            submarine.drive(command).unwrap();
+        }
+        let depths: Vec<_> = submarine.trajectory().iter().map(|p| p.depth).collect();
+        assert_eq!(vec![0, 0, -6, -6, -4], depths);
+        assert_eq!(
+            Position {
+                pos: 4,
+                depth: -4,
+                aim: 2
+            },
+            submarine.position()
+        );
+        assert_eq!(vec![2, 3, 4], submarine.above_surface());
+    }
+}
@@ -0,0 +1,550 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt;
+use std::iter;
+
+use crate::checked;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Error;
+use crate::Solution;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Bits of a line packed in words, bit `i` counting from the left is bit
+/// `i % 64` of word `i / 64`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Bits {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl fmt::Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for i in 0..self.len {
+            write!(f, "{}", if self.get(i) { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+impl Bits {
+    fn zeros(len: usize) -> Bits {
+        Bits {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, i: usize) -> bool {
+        assert!(i < self.len);
+        self.words[i / WORD_BITS] & (1 << (i % WORD_BITS)) != 0
+    }
+
+    fn set(&mut self, i: usize) {
+        assert!(i < self.len);
+        self.words[i / WORD_BITS] |= 1 << (i % WORD_BITS);
+    }
+
+    /// Indices of set bits in increasing order.
+    fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(w * WORD_BITS + bit)
+            })
+        })
+    }
+
+    fn parse(line: &Line) -> Result<Bits, ParseError> {
+        let mut bits = Bits::zeros(line.text.len());
+        for (i, c) in line.text.char_indices() {
+            match c {
+                '0' => {}
+                '1' => bits.set(i),
+                _ => return Err(line.error(&line.text[i..], "`0` or `1`")),
+            }
+        }
+        Ok(bits)
+    }
+
+    /// Number with the first bit most significant. Wider than 64 bits is an
+    /// error, also without checked mode.
+    fn to_int(&self) -> Result<u64, Error> {
+        if self.len > WORD_BITS {
+            return Err(Error::NoAnswer(format!(
+                "{}-bit number does not fit {} bits",
+                self.len, WORD_BITS
+            )));
+        }
+        Ok((0..self.len).fold(0, |result, i| (result << 1) | self.get(i) as u64))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BitsList {
+    bits_list: Vec<Bits>,
+}
+
+impl fmt::Display for BitsList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, bits) in self.bits_list.iter().enumerate() {
+            if i != 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", bits)?;
+        }
+        Ok(())
+    }
+}
+
+impl BitsList {
+    fn parse(s: &str) -> Result<BitsList, ParseError> {
+        let mut lines = parse::lines(s);
+        let first = Bits::parse(&lines.next_line("bits")?)?;
+        let mut bits_list = vec![first];
+        for line in lines {
+            let bits = Bits::parse(&line)?;
+            if bits.len() != bits_list[0].len() {
+                return Err(line.error_at_end(format!("{} bits", bits_list[0].len())));
+            }
+            bits_list.push(bits);
+        }
+        Ok(BitsList { bits_list })
+    }
+
+    fn bit_count(&self) -> usize {
+        self.bits_list[0].len()
+    }
+
+    /// Number of ones in every column, in one pass over the lines.
+    fn one_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.bit_count()];
+        for bits in &self.bits_list {
+            for i in bits.ones() {
+                counts[i] += 1;
+            }
+        }
+        counts
+    }
+}
+
+/// Most common bit among `len` bits with `ones` ones, `None` on a tie.
+fn most_common(ones: usize, len: usize) -> Option<bool> {
+    if ones * 2 == len {
+        None
+    } else {
+        Some(ones * 2 > len)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rating {
+    /// Lines with the most common bit are kept.
+    Oxygen,
+    /// Lines with the least common bit are kept.
+    Co2,
+}
+
+/// Bit kept when ones and zeros are equally common.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    PreferOne,
+    PreferZero,
+    Error,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// Number of lines with the prefix of the node.
+    count: usize,
+    children: [Option<usize>; 2],
+}
+
+/// Binary trie of lines, the root is the empty prefix.
+struct Trie {
+    nodes: Vec<Node>,
+    width: usize,
+}
+
+impl Trie {
+    fn new(lines: &BitsList) -> Trie {
+        let mut nodes = vec![Node::default()];
+        for bits in &lines.bits_list {
+            let mut node = 0;
+            nodes[node].count += 1;
+            for i in 0..bits.len() {
+                let bit = bits.get(i) as usize;
+                node = match nodes[node].children[bit] {
+                    Some(child) => child,
+                    None => {
+                        nodes.push(Node::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children[bit] = Some(child);
+                        child
+                    }
+                };
+                nodes[node].count += 1;
+            }
+        }
+        Trie {
+            nodes,
+            width: lines.bit_count(),
+        }
+    }
+
+    fn count(&self, child: Option<usize>) -> usize {
+        child.map_or(0, |c| self.nodes[c].count)
+    }
+
+    /// Line remaining after filtering by bit criteria one bit at a time.
+    fn rating(&self, rating: Rating, tie: TieBreak) -> Result<Bits, Error> {
+        let mut bits = Bits::zeros(self.width);
+        let mut node = 0;
+        for i in 0..self.width {
+            let [zeros, ones] = self.nodes[node].children.map(|c| self.count(c));
+            // With one line left, or all remaining lines having the same
+            // bit, there is nothing to choose from.
+            let bit = if zeros == 0 || ones == 0 {
+                ones != 0
+            } else if ones == zeros {
+                match tie {
+                    TieBreak::PreferOne => true,
+                    TieBreak::PreferZero => false,
+                    TieBreak::Error => {
+                        return Err(Error::Ambiguous(format!(
+                            "{:?} rating has {} lines with 0 and 1 at bit {}",
+                            rating, ones, i
+                        )))
+                    }
+                }
+            } else {
+                (ones > zeros) == (rating == Rating::Oxygen)
+            };
+            trace!(
+                "{:?} i={} zeros={} ones={} bit={}",
+                rating,
+                i,
+                zeros,
+                ones,
+                bit
+            );
+            if bit {
+                bits.set(i);
+            }
+            node = self.nodes[node].children[bit as usize].unwrap();
+        }
+        Ok(bits)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub ones: usize,
+    /// Fraction of lines with one.
+    pub ratio: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    /// Leading bits shared by the values in the bucket.
+    pub prefix: String,
+    /// Inclusive range of values, `None` if they do not fit 64 bits.
+    pub range: Option<(u64, u64)>,
+    pub count: usize,
+}
+
+/// Histograms with longer prefixes list only the buckets which occur.
+pub const DENSE_HISTOGRAM_BITS: usize = 8;
+
+/// Summary of a report for checking it before computing ratings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    pub lines: usize,
+    pub columns: Vec<Column>,
+    /// Columns with as many ones as zeros.
+    pub ties: Vec<usize>,
+    /// Lines occurring more than once with their counts, in order of the
+    /// first occurrence.
+    pub duplicates: Vec<(String, usize)>,
+    /// Values by their leading bits. Empty buckets are included only for
+    /// prefixes of at most `DENSE_HISTOGRAM_BITS` bits.
+    pub histogram: Vec<Bucket>,
+}
+
+impl Statistics {
+    /// Histogram buckets have `histogram_bits` leading bits, or one bucket
+    /// per value if lines are shorter.
+    pub fn new(lines: &BitsList, histogram_bits: usize) -> Statistics {
+        let len = lines.bits_list.len();
+        let columns: Vec<Column> = lines
+            .one_counts()
+            .into_iter()
+            .map(|ones| Column {
+                ones,
+                ratio: ones as f64 / len as f64,
+            })
+            .collect();
+        let ties = columns
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| most_common(c.ones, len).is_none())
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut counts: HashMap<&Bits, usize> = HashMap::new();
+        for bits in &lines.bits_list {
+            *counts.entry(bits).or_default() += 1;
+        }
+        let mut duplicates = Vec::new();
+        for bits in &lines.bits_list {
+            // Removed so that every line is listed once.
+            if let Some(count) = counts.remove(bits) {
+                if count > 1 {
+                    duplicates.push((bits.to_string(), count));
+                }
+            }
+        }
+
+        let width = lines.bit_count();
+        let prefix_len = histogram_bits.min(width);
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        if prefix_len <= DENSE_HISTOGRAM_BITS {
+            for b in 0..1usize << prefix_len {
+                let prefix = (0..prefix_len)
+                    .map(|i| {
+                        if b >> (prefix_len - i - 1) & 1 == 1 {
+                            '1'
+                        } else {
+                            '0'
+                        }
+                    })
+                    .collect();
+                counts.insert(prefix, 0);
+            }
+        }
+        for bits in &lines.bits_list {
+            *counts
+                .entry(bits.to_string()[..prefix_len].to_owned())
+                .or_default() += 1;
+        }
+        let histogram = counts
+            .into_iter()
+            .map(|(prefix, count)| {
+                // Prefix followed by all zeros or all ones.
+                let bound = |fill| {
+                    let chars = prefix.chars().chain(iter::repeat(fill)).take(width);
+                    chars.fold(0, |v: u64, c| (v << 1) | (c == '1') as u64)
+                };
+                let range = if width <= WORD_BITS {
+                    Some((bound('0'), bound('1')))
+                } else {
+                    None
+                };
+                Bucket {
+                    prefix,
+                    range,
+                    count,
+                }
+            })
+            .collect();
+
+        Statistics {
+            lines: len,
+            columns,
+            ties,
+            duplicates,
+            histogram,
+        }
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} lines, {} columns", self.lines, self.columns.len())?;
+        writeln!(f, "{:>6}  {:>6}  {:>5}", "column", "ones", "ratio")?;
+        for (i, column) in self.columns.iter().enumerate() {
+            let tie = if self.ties.contains(&i) { "  tie" } else { "" };
+            writeln!(
+                f,
+                "{:>6}  {:>6}  {:>5.3}{}",
+                i, column.ones, column.ratio, tie
+            )?;
+        }
+        if self.duplicates.is_empty() {
+            writeln!(f, "no duplicates")?;
+        } else {
+            writeln!(f, "duplicates:")?;
+            for (line, count) in &self.duplicates {
+                writeln!(f, "  {} x{}", line, count)?;
+            }
+        }
+        writeln!(f, "histogram:")?;
+        let width = self.columns.len();
+        for bucket in &self.histogram {
+            let pattern = format!("{:x<width$}", bucket.prefix);
+            match bucket.range {
+                Some((min, max)) => write!(f, "  {}  {:>10}..={:<10}", pattern, min, max)?,
+                None => write!(f, "  {}", pattern)?,
+            }
+            writeln!(f, "  {}", bucket.count)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = BitsList;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<BitsList, Error> {
+        Ok(BitsList::parse(input)?)
+    }
+
+    fn part1(lines: &BitsList) -> Result<u64, Error> {
+        let mut gamma = Bits::zeros(lines.bit_count());
+        let mut epsilon = Bits::zeros(lines.bit_count());
+        for (i, ones) in lines.one_counts().into_iter().enumerate() {
+            if most_common(ones, lines.bits_list.len()).unwrap_or(true) {
+                gamma.set(i);
+            } else {
+                epsilon.set(i);
+            }
+        }
+        trace!("gamma={} epsilon={}", gamma, epsilon);
+        Ok(checked::mul(gamma.to_int()?, epsilon.to_int()?)?)
+    }
+
+    fn part2(lines: &BitsList) -> Result<u64, Error> {
+        let trie = Trie::new(lines);
+        let ox = trie.rating(Rating::Oxygen, TieBreak::PreferOne)?.to_int()?;
+        let co2 = trie.rating(Rating::Co2, TieBreak::PreferZero)?.to_int()?;
+        Ok(checked::mul(ox, co2)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn bits(s: &str) -> Bits {
+        Bits::parse(&parse::lines(s).next().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn to_int() {
+        assert_eq!(Ok(23), bits("10111").to_int());
+    }
+
+    #[test]
+    fn wide() {
+        let s = format!("{}{}", "1".repeat(40), "0".repeat(60));
+        let wide = bits(&s);
+        assert_eq!(s, wide.to_string());
+        assert_eq!((0..40).collect::<Vec<_>>(), wide.ones().collect::<Vec<_>>());
+        assert_eq!(Ok(0xf000_0000_0000_0000), bits(&s[36..]).to_int());
+        assert_eq!(
+            Err(Error::NoAnswer(
+                "65-bit number does not fit 64 bits".to_owned()
+            )),
+            bits(&s[35..]).to_int()
+        );
+    }
+
+    #[test]
+    fn ratings() {
+        let input = fs::read_to_string("inputs/day03/example.txt").unwrap();
+        let trie = Trie::new(&BitsList::parse(&input).unwrap());
+        let rating = |rating, tie| trie.rating(rating, tie).map(|b| b.to_string());
+        assert_eq!(
+            Ok("10111".to_owned()),
+            rating(Rating::Oxygen, TieBreak::PreferOne)
+        );
+        assert_eq!(
+            Ok("10110".to_owned()),
+            rating(Rating::Oxygen, TieBreak::PreferZero)
+        );
+        assert_eq!(
+            Ok("01010".to_owned()),
+            rating(Rating::Co2, TieBreak::PreferZero)
+        );
+        assert_eq!(
+            Err(Error::Ambiguous(
+                "Oxygen rating has 1 lines with 0 and 1 at bit 4".to_owned()
+            )),
+            rating(Rating::Oxygen, TieBreak::Error)
+        );
+    }
+
+    #[test]
+    fn statistics() {
+        let input = fs::read_to_string("inputs/day03/example.txt").unwrap();
+        let stats = Statistics::new(&BitsList::parse(&input).unwrap(), 2);
+        let ones: Vec<_> = stats.columns.iter().map(|c| c.ones).collect();
+        assert_eq!(vec![7, 5, 8, 7, 5], ones);
+        assert!(stats.ties.is_empty());
+        assert!(stats.duplicates.is_empty());
+        assert_eq!(
+            Bucket {
+                prefix: "01".to_owned(),
+                range: Some((8, 15)),
+                count: 2,
+            },
+            stats.histogram[1]
+        );
+        let counts: Vec<_> = stats.histogram.iter().map(|b| b.count).collect();
+        assert_eq!(vec![3, 2, 4, 3], counts);
+
+        let stats = Statistics::new(&BitsList::parse("10\n10\n01\n00\n").unwrap(), 4);
+        assert_eq!(vec![0], stats.ties);
+        assert_eq!(vec![("10".to_owned(), 2)], stats.duplicates);
+        assert_eq!(
+            "\
+4 lines, 2 columns
+column    ones  ratio
+     0       2  0.500  tie
+     1       1  0.250
+duplicates:
+  10 x2
+histogram:
+  00           0..=0           1
+  01           1..=1           1
+  10           2..=2           2
+  11           3..=3           0
+",
+            stats.to_string()
+        );
+
+        // Only buckets which occur.
+        let wide = format!("{}\n{}\n", "1".repeat(70), "0".repeat(70));
+        let stats = Statistics::new(&BitsList::parse(&wide).unwrap(), 64);
+        let prefixes: Vec<_> = stats.histogram.iter().map(|b| b.prefix.len()).collect();
+        assert_eq!(vec![64, 64], prefixes);
+        assert_eq!(None, stats.histogram[0].range);
+    }
+
+    #[test]
+    fn one_counts() {
+        let input = format!("{0}1\n{0}0\n0{1}\n", "1".repeat(70), "0".repeat(70));
+        let lines = BitsList::parse(&input).unwrap();
+        let mut expected = vec![2; 71];
+        expected[70] = 1;
+        assert_eq!(expected, lines.one_counts());
+    }
+}
@@ -0,0 +1,459 @@
+use std::collections::HashSet;
+
+use crate::checked;
+use crate::checked::Overflow;
+use crate::grid::Grid;
+use crate::grid::Pos;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
+
+#[derive(Debug, Clone)]
+pub struct BingoBoard {
+    pub numbers: Grid<u32>,
+}
+
+impl BingoBoard {
+    pub fn find_number(&self, number: u32) -> Vec<Pos> {
+        self.numbers
+            .iter()
+            .filter(|(_, &n)| n == number)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BingoFile {
+    pub numbers: Vec<u32>,
+    /// All boards have the size of the first one.
+    pub boards: Vec<BingoBoard>,
+}
+
+impl BingoFile {
+    pub fn parse(content: &str) -> Result<BingoFile, ParseError> {
+        let mut lines = parse::lines(content);
+
+        let line = lines.next_line("numbers")?;
+        let mut numbers = Vec::new();
+        let mut seen = HashSet::new();
+        for s in line.text.split(',') {
+            let number: u32 = line.parse(s, "number")?;
+            if !seen.insert(number) {
+                return Err(line.error(s, "unique number"));
+            }
+            numbers.push(number);
+        }
+
+        // Boards are separated by empty lines, the size of the first board
+        // is the size of all boards.
+        let lines: Vec<Line> = lines.collect();
+        let mut boards: Vec<BingoBoard> = Vec::new();
+        let mut i = 0;
+        while i < lines.len() {
+            lines[i].expect("")?;
+            let start = i + 1;
+            i = start;
+            while i < lines.len() && !lines[i].text.is_empty() {
+                i += 1;
+            }
+            let board_lines = &lines[start..i];
+            let size = boards.first().map(|b| (b.numbers.rows(), b.numbers.cols()));
+            match (board_lines.last(), size) {
+                (None, _) => {
+                    return Err(ParseError::new(lines[start - 1].number + 1, 1, "board row"))
+                }
+                (Some(last), Some((rows, _))) if board_lines.len() < rows => {
+                    return Err(ParseError::new(last.number + 1, 1, "board row"))
+                }
+                (_, Some((rows, _))) if board_lines.len() > rows => {
+                    let extra = &board_lines[rows];
+                    return Err(extra.error(extra.text, "empty line"));
+                }
+                _ => {}
+            }
+
+            let mut rows: Vec<Vec<u32>> = Vec::new();
+            for line in board_lines {
+                let words: Vec<&str> = line.text.split_whitespace().collect();
+                let cols = match (size, rows.first()) {
+                    (Some((_, cols)), _) => cols,
+                    (None, Some(first)) => first.len(),
+                    (None, None) => words.len(),
+                };
+                if words.len() > cols {
+                    return Err(line.error(words[cols], "end of line"));
+                }
+                if words.len() < cols || cols == 0 {
+                    return Err(line.error_at_end(format!("{} numbers", cols.max(1))));
+                }
+                let row = words
+                    .iter()
+                    .map(|s| line.parse(s, "number"))
+                    .collect::<Result<Vec<u32>, _>>()?;
+                rows.push(row);
+            }
+            boards.push(BingoBoard {
+                numbers: Grid::from_rows(rows),
+            });
+        }
+
+        Ok(BingoFile { numbers, boards })
+    }
+}
+
+/// Condition on marked cells of a board for it to win.
+pub trait WinRule {
+    fn is_win(&self, marks: &Grid<bool>) -> bool;
+}
+
+/// All cells in a row are marked.
+pub struct Row;
+
+impl WinRule for Row {
+    fn is_win(&self, marks: &Grid<bool>) -> bool {
+        (0..marks.rows()).any(|r| marks.row(r).iter().all(|&m| m))
+    }
+}
+
+/// All cells in a column are marked.
+pub struct Column;
+
+impl WinRule for Column {
+    fn is_win(&self, marks: &Grid<bool>) -> bool {
+        (0..marks.cols()).any(|c| marks.col(c).all(|&m| m))
+    }
+}
+
+/// All cells of one of the two diagonals are marked. Only square boards
+/// have diagonals.
+pub struct Diagonal;
+
+impl WinRule for Diagonal {
+    fn is_win(&self, marks: &Grid<bool>) -> bool {
+        let n = marks.rows();
+        n == marks.cols()
+            && ((0..n).all(|i| marks[(i, i)]) || (0..n).all(|i| marks[(i, n - 1 - i)]))
+    }
+}
+
+/// The four corner cells are marked.
+pub struct Corners;
+
+impl WinRule for Corners {
+    fn is_win(&self, marks: &Grid<bool>) -> bool {
+        let (r, c) = (marks.rows() - 1, marks.cols() - 1);
+        [(0, 0), (0, c), (r, 0), (r, c)]
+            .iter()
+            .all(|&pos| marks[pos])
+    }
+}
+
+/// All cells are marked.
+pub struct Full;
+
+impl WinRule for Full {
+    fn is_win(&self, marks: &Grid<bool>) -> bool {
+        marks.values().all(|&m| m)
+    }
+}
+
+/// At least `k` cells in a row or a column are marked.
+pub struct KOfLine(pub usize);
+
+impl WinRule for KOfLine {
+    fn is_win(&self, marks: &Grid<bool>) -> bool {
+        let count = |line: &mut dyn Iterator<Item = &bool>| line.filter(|&&m| m).count();
+        (0..marks.rows()).any(|r| count(&mut marks.row(r).iter()) >= self.0)
+            || (0..marks.cols()).any(|c| count(&mut marks.col(c)) >= self.0)
+    }
+}
+
+/// Rules of the puzzle.
+pub const ROW_OR_COLUMN: &[&dyn WinRule] = &[&Row, &Column];
+
+#[derive(Debug, Clone)]
+pub struct BingoMarks {
+    pub marks: Grid<bool>,
+}
+
+impl BingoMarks {
+    pub fn new(board: &BingoBoard) -> BingoMarks {
+        BingoMarks {
+            marks: board.numbers.map(|_| false),
+        }
+    }
+
+    /// Whether any of `rules` is satisfied.
+    pub fn is_win(&self, rules: &[&dyn WinRule]) -> bool {
+        rules.iter().any(|rule| rule.is_win(&self.marks))
+    }
+}
+
+pub struct BingoBoardWithMarks<'a> {
+    pub board: &'a BingoBoard,
+    pub marks: &'a mut BingoMarks,
+}
+
+impl BingoBoardWithMarks<'_> {
+    pub fn mark(&mut self, number: u32) {
+        for pos in self.board.find_number(number) {
+            self.marks.marks[pos] = true;
+        }
+    }
+
+    pub fn sum_of_all_unmarked_numbers(&self) -> Result<u32, Overflow> {
+        checked::sum(
+            self.board
+                .numbers
+                .iter()
+                .filter(|&(pos, _)| !self.marks.marks[pos])
+                .map(|(_, &n)| n),
+        )
+    }
+}
+
+/// When and how a board won.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    /// Index of the winning number in the drawn numbers.
+    pub draw: usize,
+    pub number: u32,
+    pub unmarked: u32,
+    pub score: u32,
+}
+
+/// Outcome of a whole game, played until all numbers are drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline {
+    /// Win of every board, `None` for boards that never win.
+    pub wins: Vec<Option<Win>>,
+}
+
+impl Timeline {
+    pub fn play(file: &BingoFile, rules: &[&dyn WinRule]) -> Result<Timeline, Overflow> {
+        let mut marks: Vec<BingoMarks> = file.boards.iter().map(BingoMarks::new).collect();
+        let mut wins = vec![None; file.boards.len()];
+        for (draw, &number) in file.numbers.iter().enumerate() {
+            for ((board, marks), win) in file.boards.iter().zip(&mut marks).zip(&mut wins) {
+                if win.is_some() {
+                    continue;
+                }
+                let mut board = BingoBoardWithMarks { board, marks };
+                board.mark(number);
+                if board.marks.is_win(rules) {
+                    let unmarked = board.sum_of_all_unmarked_numbers()?;
+                    *win = Some(Win {
+                        draw,
+                        number,
+                        unmarked,
+                        score: checked::mul(number, unmarked)?,
+                    });
+                }
+            }
+        }
+        Ok(Timeline { wins })
+    }
+
+    /// Indices of boards with their wins, in order of winning. Boards
+    /// winning on the same draw are in order of index.
+    pub fn order(&self) -> Vec<(usize, Win)> {
+        let mut order: Vec<(usize, Win)> = self
+            .wins
+            .iter()
+            .enumerate()
+            .filter_map(|(i, win)| win.map(|win| (i, win)))
+            .collect();
+        order.sort_by_key(|&(i, win)| (win.draw, i));
+        order
+    }
+
+    pub fn never_win(&self) -> Vec<usize> {
+        (0..self.wins.len())
+            .filter(|&i| self.wins[i].is_none())
+            .collect()
+    }
+
+    /// Boards winning on the first winning draw.
+    pub fn first(&self) -> Vec<(usize, Win)> {
+        let order = self.order();
+        let draw = order.first().map(|(_, win)| win.draw);
+        order
+            .into_iter()
+            .filter(|(_, win)| Some(win.draw) == draw)
+            .collect()
+    }
+
+    /// Boards winning on the last winning draw.
+    pub fn last(&self) -> Vec<(usize, Win)> {
+        let order = self.order();
+        let draw = order.last().map(|(_, win)| win.draw);
+        order
+            .into_iter()
+            .filter(|(_, win)| Some(win.draw) == draw)
+            .collect()
+    }
+}
+
+/// Score of the only board in `winners`.
+fn score(winners: &[(usize, Win)], which: &str) -> Result<u32, Error> {
+    match winners {
+        [] => Err(Error::NoAnswer("no board wins".to_owned())),
+        [(_, win)] => Ok(win.score),
+        [(_, win), ..] => Err(Error::Ambiguous(format!(
+            "{} boards win {} on draw {}",
+            winners.len(),
+            which,
+            win.draw
+        ))),
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = BingoFile;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<BingoFile, Error> {
+        Ok(BingoFile::parse(input)?)
+    }
+
+    fn part1(file: &BingoFile) -> Result<u32, Error> {
+        score(&Timeline::play(file, ROW_OR_COLUMN)?.first(), "first")
+    }
+
+    fn part2(file: &BingoFile) -> Result<u32, Error> {
+        score(&Timeline::play(file, ROW_OR_COLUMN)?.last(), "last")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+3,1,4,15,9,2,6
+
+ 1  2  3  4
+ 5  6  7  8
+ 9 10 11 12
+
+ 3 15  6 11
+ 9  1  2  4
+ 7  8 10 12
+";
+
+    /// Like `SMALL`, but the boards win on different draws.
+    const TIMELINE: &str = "\
+3,1,4,15,9,2,6
+
+ 1  2  3  4
+ 5  6  7  8
+ 9 10 11 12
+
+ 3 15  6 11
+ 5  1  4  8
+ 7 12  9 10
+";
+
+    fn marks_after(board: &BingoBoard, numbers: &[u32]) -> BingoMarks {
+        let mut marks = BingoMarks::new(board);
+        let mut board = BingoBoardWithMarks {
+            board,
+            marks: &mut marks,
+        };
+        for &n in numbers {
+            board.mark(n);
+        }
+        marks
+    }
+
+    #[test]
+    fn sizes() {
+        let file = BingoFile::parse(SMALL).unwrap();
+        assert_eq!(2, file.boards.len());
+        assert_eq!(
+            (3, 4),
+            (file.boards[1].numbers.rows(), file.boards[1].numbers.cols())
+        );
+        assert_eq!(vec![(1, 0)], file.boards[1].find_number(9));
+    }
+
+    #[test]
+    fn rules() {
+        let file = BingoFile::parse(SMALL).unwrap();
+        let board = &file.boards[0];
+        let marks = marks_after(board, &[1, 4, 9, 12]);
+        assert!(marks.is_win(&[&Corners]));
+        assert!(!marks.is_win(ROW_OR_COLUMN));
+        assert!(marks.is_win(&[&KOfLine(2)]));
+        assert!(!marks.is_win(&[&KOfLine(3)]));
+        assert!(marks_after(board, &[4, 8, 12]).is_win(&[&Column]));
+        assert!(!marks_after(board, &[1, 2, 3]).is_win(&[&Column]));
+        assert!(!marks_after(board, &(1..12).collect::<Vec<_>>()).is_win(&[&Full]));
+        assert!(marks_after(board, &(1..=12).collect::<Vec<_>>()).is_win(&[&Full]));
+        // Not square.
+        assert!(!marks_after(board, &(1..=12).collect::<Vec<_>>()).is_win(&[&Diagonal]));
+
+        let square = BingoFile::parse("1\n\n1 2\n3 4\n").unwrap();
+        assert!(marks_after(&square.boards[0], &[2, 3]).is_win(&[&Diagonal]));
+        assert!(!marks_after(&square.boards[0], &[1, 2]).is_win(&[&Diagonal]));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| BingoFile::parse(input).unwrap_err();
+        assert_eq!(ParseError::new(4, 2, "2 numbers"), error("1\n\n1 2\n3\n"));
+        assert_eq!(
+            ParseError::new(6, 5, "end of line"),
+            error("1\n\n1 2\n3 4\n\n5 6 7\n8 9\n")
+        );
+        assert_eq!(
+            ParseError::new(7, 1, "board row"),
+            error("1\n\n1 2\n3 4\n\n5 6\n")
+        );
+        assert_eq!(
+            ParseError::new(8, 1, "empty line"),
+            error("1\n\n1 2\n3 4\n\n5 6\n7 8\n9 10\n")
+        );
+    }
+
+    #[test]
+    fn timeline() {
+        let file = BingoFile::parse(TIMELINE).unwrap();
+        let timeline = Timeline::play(&file, ROW_OR_COLUMN).unwrap();
+        // The first board wins by row on 2, the second by column on 6.
+        let second = Win {
+            draw: 6,
+            number: 6,
+            unmarked: 11 + 5 + 8 + 7 + 12 + 10,
+            score: 6 * 53,
+        };
+        assert_eq!(vec![(0, 5), (1, 6)], draws(&timeline.order()));
+        assert_eq!(Some(second), timeline.wins[1]);
+        assert_eq!(vec![(1, second)], timeline.last());
+        assert!(timeline.never_win().is_empty());
+
+        let timeline = Timeline::play(&file, &[&Full]).unwrap();
+        assert_eq!(vec![0, 1], timeline.never_win());
+        assert_eq!(
+            Error::NoAnswer("no board wins".to_owned()),
+            score(&timeline.first(), "first").unwrap_err()
+        );
+
+        let timeline = Timeline::play(&file, &[&KOfLine(1)]).unwrap();
+        assert_eq!(vec![(0, 0), (1, 0)], draws(&timeline.first()));
+        assert_eq!(
+            Error::Ambiguous("2 boards win first on draw 0".to_owned()),
+            score(&timeline.first(), "first").unwrap_err()
+        );
+    }
+
+    fn draws(wins: &[(usize, Win)]) -> Vec<(usize, usize)> {
+        wins.iter().map(|&(i, win)| (i, win.draw)).collect()
+    }
+}
@@ -1,5 +1,9 @@
-use std::fs;
-use std::path::Path;
+use crate::grid::Grid;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
 
 #[derive(Debug, Clone, Copy)]
 pub struct VentCoord {
@@ -8,12 +12,11 @@ pub struct VentCoord {
 }
 
 impl VentCoord {
-    fn parse(s: &str) -> VentCoord {
-        let mut iter = s.split(',');
-        let x = iter.next().unwrap().parse::<usize>().unwrap();
-        let y = iter.next().unwrap().parse::<usize>().unwrap();
-        assert!(iter.next().is_none());
-        VentCoord { x, y }
+    fn parse(line: &Line, s: &str) -> Result<VentCoord, ParseError> {
+        let (x, y) = line.split_once(s, ",")?;
+        let x = line.parse(x, "x coordinate")?;
+        let y = line.parse(y, "y coordinate")?;
+        Ok(VentCoord { x, y })
     }
 }
 
@@ -24,12 +27,11 @@ pub struct VentLine {
 }
 
 impl VentLine {
-    fn parse(s: &str) -> VentLine {
-        let parts: Vec<&str> = s.split(" -> ").collect();
-        assert_eq!(2, parts.len());
-        let from = VentCoord::parse(parts[0]);
-        let to = VentCoord::parse(parts[1]);
-        VentLine { from, to }
+    fn parse(line: &Line) -> Result<VentLine, ParseError> {
+        let (from, to) = line.split_once(line.text, " -> ")?;
+        let from = VentCoord::parse(line, from)?;
+        let to = VentCoord::parse(line, to)?;
+        Ok(VentLine { from, to })
     }
 
     pub fn is_vert(&self) -> bool {
@@ -51,19 +53,18 @@ pub struct Vents {
 }
 
 impl Vents {
-    pub fn parse(filename: &Path) -> Vents {
-        let content = fs::read_to_string(filename).unwrap();
-        let lines = content.lines().map(|s| VentLine::parse(s)).collect();
-        Vents { lines }
+    pub fn parse(content: &str) -> Result<Vents, ParseError> {
+        let lines = parse::lines(content)
+            .map(|line| VentLine::parse(&line))
+            .collect::<Result<_, _>>()?;
+        Ok(Vents { lines })
     }
 
     pub fn take_vert_or_horiz(&self) -> Vents {
         let mut lines = Vec::new();
         for line in &self.lines {
-            if line.is_vert() {
-                lines.push(line.clone());
-            } else if line.is_horiz() {
-                lines.push(line.clone());
+            if line.is_vert() || line.is_horiz() {
+                lines.push(*line);
             }
         }
         Vents { lines }
@@ -84,12 +85,12 @@ impl Vents {
 
 #[derive(Clone)]
 pub struct VentsGrid {
-    pub grid: Vec<Vec<u32>>,
+    pub grid: Grid<u32>,
 }
 
 impl VentsGrid {
     fn put_point(&mut self, x: usize, y: usize) {
-        self.grid[y][x] += 1;
+        self.grid[(y, x)] += 1;
     }
 
     fn between(a: usize, b: usize) -> impl Iterator<Item = usize> {
@@ -110,7 +111,7 @@ impl VentsGrid {
                 self.put_point(x, line.from.y);
             }
         } else if line.is_diag() {
-            let len = (line.from.x as i32 - line.to.x as i32).abs() as usize;
+            let len = line.from.x.abs_diff(line.to.x);
             let xs = if line.to.x > line.from.x { 1 } else { -1 };
             let ys = if line.to.y > line.from.y { 1 } else { -1 };
             for i in 0..=len {
@@ -124,35 +125,39 @@ impl VentsGrid {
     }
 
     pub fn count_gt_1(&self) -> usize {
-        self.grid
-            .iter()
-            .map(|row| row.iter().filter(|&&v| v > 1).count())
-            .sum()
+        self.grid.values().filter(|&&v| v > 1).count()
     }
 }
 
-fn run(filename: &str) {
-    println!("{}", filename);
-    let vents = Vents::parse(Path::new(filename));
+fn count_overlaps(vents: &Vents, with_diag: bool) -> usize {
     let (max_x, max_y) = vents.max_xy();
-    let mut grid_hv = VentsGrid {
-        grid: vec![vec![0; max_x + 1]; max_y + 1],
+    let mut grid = VentsGrid {
+        grid: Grid::new(max_y + 1, max_x + 1, 0),
     };
-    let mut grid_hvd = grid_hv.clone();
     for line in &vents.lines {
-        if line.is_horiz() || line.is_vert() {
-            grid_hv.put_line(line);
-            grid_hvd.put_line(line);
-        }
-        if line.is_diag() {
-            grid_hvd.put_line(line);
+        if line.is_horiz() || line.is_vert() || (with_diag && line.is_diag()) {
+            grid.put_line(line);
         }
     }
-    println!("hv: {}", grid_hv.count_gt_1());
-    println!("hvd: {}", grid_hvd.count_gt_1());
+    grid.count_gt_1()
 }
 
-fn main() {
-    run("day05-input-test.txt");
-    run("day05-input.txt");
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vents;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vents, Error> {
+        Ok(Vents::parse(input)?)
+    }
+
+    fn part1(vents: &Vents) -> Result<usize, Error> {
+        Ok(count_overlaps(vents, false))
+    }
+
+    fn part2(vents: &Vents) -> Result<usize, Error> {
+        Ok(count_overlaps(vents, true))
+    }
 }
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use crate::checked;
+use crate::checked::Overflow;
+use crate::parse;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Simulate;
+use crate::Solution;
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct State {
+    count_by_day: [u64; 9],
+}
+
+impl State {
+    fn count(&self) -> Result<u64, Overflow> {
+        checked::sum(self.count_by_day)
+    }
+}
+
+/// Number of fish by timer, and the total.
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (timer, count) in self.count_by_day.iter().enumerate() {
+            writeln!(f, "{}: {}", timer, count)?;
+        }
+        match self.count() {
+            Ok(total) => write!(f, "total: {}", total),
+            Err(e) => write!(f, "total: {}", e),
+        }
+    }
+}
+
+fn update_state(state: &State) -> Result<State, Overflow> {
+    let mut r = State::default();
+    for (x, &count) in state.count_by_day.iter().enumerate() {
+        let next: &[usize] = if x != 0 { &[x - 1] } else { &[6, 8] };
+        for &y in next {
+            r.count_by_day[y] = checked::add(r.count_by_day[y], count)?;
+        }
+    }
+    Ok(r)
+}
+
+fn parse(input: &str) -> Result<State, ParseError> {
+    let line = parse::lines(input).next_line("timers")?;
+    let mut state = State::default();
+    for x in line.text.trim_end().split(',') {
+        let timer: usize = line.parse(x, "timer")?;
+        if timer >= state.count_by_day.len() {
+            return Err(line.error(x, "timer from 0 to 8"));
+        }
+        state.count_by_day[timer] += 1;
+    }
+    Ok(state)
+}
+
+fn count_after(state: &State, days: u32) -> Result<u64, Overflow> {
+    let mut state = *state;
+    for _ in 0..days {
+        state = update_state(&state)?;
+    }
+    state.count()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = State;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<State, Error> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(state: &State) -> Result<u64, Error> {
+        Ok(count_after(state, 80)?)
+    }
+
+    fn part2(state: &State) -> Result<u64, Error> {
+        Ok(count_after(state, 256)?)
+    }
+}
+
+impl Simulate for Day06 {
+    type State = State;
+
+    fn start(state: &State) -> State {
+        *state
+    }
+
+    /// Fish never stop spawning.
+    fn step(_input: &State, state: &State) -> Result<Option<State>, Error> {
+        Ok(Some(update_state(state)?))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::checked;
+use crate::checked::Overflow;
+use crate::parse;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Error;
+use crate::Solution;
+
+fn parse_count_by_x(input: &str) -> Result<Vec<u64>, ParseError> {
+    let input = parse::comma_separated::<usize>(input)?;
+    let max = input.iter().max().unwrap();
+    let mut count_by_x = vec![0; *max + 1];
+    for x in &input {
+        count_by_x[*x] += 1;
+    }
+    Ok(count_by_x)
+}
+
+fn fuel_for_len(len: u64) -> Result<u64, Overflow> {
+    Ok(checked::mul(len, checked::add(len, 1)?)? / 2)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u64>, Error> {
+        Ok(parse_count_by_x(input)?)
+    }
+
+    fn part1(count_by_x: &Vec<u64>) -> Result<u64, Error> {
+        trace!("count_by_x: {:?}", count_by_x);
+
+        let mut fuel_to_left = vec![0; count_by_x.len()];
+        let mut fuel_to_right = vec![0; count_by_x.len()];
+        let mut fuel_to_left_sum = 0;
+        let mut count_to_left_sum = 0;
+        for (i, count) in count_by_x.iter().copied().enumerate() {
+            fuel_to_left[i] = fuel_to_left_sum;
+            count_to_left_sum = checked::add(count_to_left_sum, count)?;
+            fuel_to_left_sum = checked::add(fuel_to_left_sum, count_to_left_sum)?;
+        }
+        let mut fuel_to_right_sum = 0;
+        let mut count_to_right_sum = 0;
+        for (i, count) in count_by_x.iter().copied().enumerate().rev() {
+            fuel_to_right[i] = fuel_to_right_sum;
+            count_to_right_sum = checked::add(count_to_right_sum, count)?;
+            fuel_to_right_sum = checked::add(fuel_to_right_sum, count_to_right_sum)?;
+        }
+
+        trace!("fuel_to_left: {:?}", fuel_to_left);
+        trace!("fuel_to_right: {:?}", fuel_to_right);
+
+        let mut min_fuel = u64::MAX;
+        for i in 0..count_by_x.len() {
+            let fuel = checked::add(fuel_to_left[i], fuel_to_right[i])?;
+            if fuel < min_fuel {
+                min_fuel = fuel;
+            }
+        }
+        Ok(min_fuel)
+    }
+
+    fn part2(count_by_x: &Vec<u64>) -> Result<u64, Error> {
+        let mut min_fuel = u64::MAX;
+        for i in 0..count_by_x.len() {
+            let mut fuel_to_left = 0;
+            let mut fuel_to_right = 0;
+            for (j, count) in count_by_x.iter().enumerate().take(i) {
+                let fuel = checked::mul(*count, fuel_for_len((i - j) as u64)?)?;
+                fuel_to_left = checked::add(fuel_to_left, fuel)?;
+            }
+            for (j, count) in count_by_x.iter().enumerate().skip(i + 1) {
+                let fuel = checked::mul(*count, fuel_for_len((j - i) as u64)?)?;
+                fuel_to_right = checked::add(fuel_to_right, fuel)?;
+            }
+            let fuel = checked::add(fuel_to_left, fuel_to_right)?;
+            if fuel < min_fuel {
+                min_fuel = fuel;
+            }
+        }
+        Ok(min_fuel)
+    }
+}
@@ -1,6 +1,13 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs;
+
+use crate::checked;
+use crate::debug;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Copy, Clone, Hash)]
 struct UnknownWire {
@@ -8,12 +15,13 @@ struct UnknownWire {
 }
 
 impl UnknownWire {
-    fn parse(c: char) -> UnknownWire {
-        assert!(c >= 'a');
-        assert!(c <= 'g');
-        UnknownWire {
-            value: c as u8 - 'a' as u8,
+    fn parse(c: char) -> Option<UnknownWire> {
+        if !('a'..='g').contains(&c) {
+            return None;
         }
+        Some(UnknownWire {
+            value: c as u8 - b'a',
+        })
     }
 }
 
@@ -23,10 +31,19 @@ struct Pattern {
 }
 
 impl Pattern {
-    fn parse(s: &str) -> Pattern {
-        let mut wires: Vec<UnknownWire> = s.chars().map(|c| UnknownWire::parse(c)).collect();
+    fn parse(line: &Line, s: &str) -> Result<Pattern, ParseError> {
+        let mut wires = Vec::new();
+        for (i, c) in s.char_indices() {
+            match UnknownWire::parse(c) {
+                Some(wire) => wires.push(wire),
+                None => return Err(line.error(&s[i..], "wire from `a` to `g`")),
+            }
+        }
+        if wires.is_empty() {
+            return Err(line.error(s, "pattern"));
+        }
         wires.sort();
-        Pattern { wires }
+        Ok(Pattern { wires })
     }
 
     fn to_set(&self) -> HashSet<UnknownWire> {
@@ -56,37 +73,30 @@ impl Pattern {
     }
 }
 
-struct Line {
+pub struct Entry {
     left: Vec<Pattern>,
     right: Vec<Pattern>,
 }
 
-impl Line {
-    fn parse(s: &str) -> Line {
-        let parts: Vec<_> = s.split(" | ").collect();
-        assert_eq!(2, parts.len());
-        let left: Vec<_> = parts[0].split(" ").map(|s| Pattern::parse(s)).collect();
-        let right: Vec<_> = parts[1].split(" ").map(|s| Pattern::parse(s)).collect();
-        assert_eq!(10, left.len());
-        assert_eq!(4, right.len());
-        Line { left, right }
-    }
-}
-
-fn part1(filename: &str) {
-    println!("{}", filename);
-    let content = fs::read_to_string(filename).expect("Something went wrong reading the file");
-    let lines: Vec<_> = content.lines().map(|s| Line::parse(s)).collect();
-    let mut count = 0;
-    for line in &lines {
-        count += line
-            .right
-            .iter()
-            .filter(|p| p.is_1() || p.is_4() || p.is_7() || p.is_8())
-            .count();
+impl Entry {
+    fn parse(line: &Line) -> Result<Entry, ParseError> {
+        let (left, right) = line.split_once(line.text, " | ")?;
+        let left = left
+            .split(' ')
+            .map(|s| Pattern::parse(line, s))
+            .collect::<Result<Vec<_>, _>>()?;
+        let right = right
+            .split(' ')
+            .map(|s| Pattern::parse(line, s))
+            .collect::<Result<Vec<_>, _>>()?;
+        if left.len() != 10 {
+            return Err(line.error(line.text, "10 patterns"));
+        }
+        if right.len() != 4 {
+            return Err(line.error_at_end("4 output patterns"));
+        }
+        Ok(Entry { left, right })
     }
-    assert!(count == 26 || count == 514);
-    println!("{}", count);
 }
 
 struct Patterns<'a> {
@@ -191,38 +201,52 @@ fn decode(patterns: &[Pattern]) -> Vec<&Pattern> {
     vec![p0, p1, p2, p3, p4, p5, p6, p7, p8, p9]
 }
 
-fn part2(filename: &str) {
-    println!("{}", filename);
-    let content = fs::read_to_string(filename).expect("Something went wrong reading the file");
-    let lines: Vec<_> = content.lines().map(|s| Line::parse(s)).collect();
-    let mut sum = 0;
-    for line in &lines {
-        let decoded = decode(&line.left);
-        let decoded_index: HashMap<&Pattern, u32> = decoded
-            .iter()
-            .copied()
-            .enumerate()
-            .map(|(i, p)| (p, i as u32))
-            .collect();
-        let digits = line
-            .right
-            .iter()
-            .map(|d| decoded_index[&d])
-            .collect::<Vec<_>>();
-        let dec_value = digits.iter().fold(0, |acc, &d| acc * 10 + d);
-        // println!("{}", dec_value);
-        sum += dec_value;
-    }
-    println!("{}", sum);
-}
+pub struct Day08;
 
-fn main() {
-    println!("Part 1");
-    part1("day08-input-test.txt");
-    part1("day08-input.txt");
+impl Solution for Day08 {
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = u32;
 
-    println!();
-    println!("Part 2");
-    part2("day08-input-test.txt");
-    part2("day08-input.txt");
+    fn parse(input: &str) -> Result<Vec<Entry>, Error> {
+        Ok(parse::lines(input)
+            .map(|line| Entry::parse(&line))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(entries: &Vec<Entry>) -> Result<usize, Error> {
+        let mut count = 0;
+        for entry in entries {
+            count += entry
+                .right
+                .iter()
+                .filter(|p| p.is_1() || p.is_4() || p.is_7() || p.is_8())
+                .count();
+        }
+        Ok(count)
+    }
+
+    fn part2(entries: &Vec<Entry>) -> Result<u32, Error> {
+        let mut sum = 0;
+        for entry in entries {
+            let decoded = decode(&entry.left);
+            let decoded_index: HashMap<&Pattern, u32> = decoded
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, p)| (p, i as u32))
+                .collect();
+            let digits = entry
+                .right
+                .iter()
+                .map(|d| decoded_index[d])
+                .collect::<Vec<_>>();
+            let dec_value = digits
+                .iter()
+                .try_fold(0, |acc, &d| checked::add(checked::mul(acc, 10)?, d))?;
+            debug!("{}", dec_value);
+            sum = checked::add(sum, dec_value)?;
+        }
+        Ok(sum)
+    }
 }
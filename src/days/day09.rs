@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use crate::checked;
+use crate::grid::Edges;
+use crate::grid::Grid;
+use crate::grid::Pos;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
+
+pub struct Map {
+    grid: Grid<u8>,
+}
+
+impl Map {
+    fn parse(content: &str) -> Result<Map, ParseError> {
+        let grid = Grid::from_digits(content)?;
+        Ok(Map { grid })
+    }
+
+    fn is_low_point(&self, pos: Pos) -> bool {
+        self.grid
+            .neighbours4(pos, Edges::Bounded)
+            .all(|n| self.grid[n] > self.grid[pos])
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Map;
+    type Answer1 = u64;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Map, Error> {
+        Ok(Map::parse(input)?)
+    }
+
+    fn part1(map: &Map) -> Result<u64, Error> {
+        let mut res = 0;
+        for (pos, height) in map.grid.iter() {
+            if map.is_low_point(pos) {
+                res = checked::add(res, *height as u64 + 1)?;
+            }
+        }
+        Ok(res)
+    }
+
+    fn part2(map: &Map) -> Result<u32, Error> {
+        let mut basin_ids_by_cell = HashMap::new();
+
+        let mut basins = Vec::new();
+
+        fn find_basin_id(
+            map: &Map,
+            pos: Pos,
+            basin_id: u32,
+            basins: &mut HashMap<Pos, u32>,
+            current_basin: &mut u32,
+        ) {
+            assert_ne!(map.grid[pos], 9);
+
+            if basins.contains_key(&pos) {
+                return;
+            }
+
+            basins.insert(pos, basin_id);
+            *current_basin += 1;
+
+            for n in map.grid.neighbours4(pos, Edges::Bounded) {
+                if map.grid[n] != 9 {
+                    find_basin_id(map, n, basin_id, basins, current_basin);
+                }
+            }
+        }
+
+        for (pos, height) in map.grid.iter() {
+            if basin_ids_by_cell.contains_key(&pos) {
+                continue;
+            }
+            if *height == 9 {
+                continue;
+            }
+            let basin_id = basins.len() as u32;
+            basins.push(0);
+            let current_basin = basins.last_mut().unwrap();
+            find_basin_id(map, pos, basin_id, &mut basin_ids_by_cell, current_basin);
+        }
+        basins.sort_by_key(|&x| u32::MAX - x);
+        basins.drain(3..);
+        Ok(checked::product(basins)?)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::checked;
+use crate::checked::Overflow;
+use crate::parse;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
+
+enum Validate {
+    Invalid(u64),
+    Complete(u64),
+}
+
+fn validate(s: &str) -> Result<Validate, Overflow> {
+    let mut stack = Vec::new();
+    for b in s.bytes() {
+        match b {
+            b'{' | b'[' | b'(' | b'<' => stack.push(b),
+            b => {
+                // Closing bracket without opening one is corrupted too.
+                let pop = stack.pop().unwrap_or(0);
+                match (b, pop) {
+                    (b'}', b'{') | (b']', b'[') | (b')', b'(') | (b'>', b'<') => (),
+                    (b')', _) => return Ok(Validate::Invalid(3)),
+                    (b']', _) => return Ok(Validate::Invalid(57)),
+                    (b'}', _) => return Ok(Validate::Invalid(1197)),
+                    (b'>', _) => return Ok(Validate::Invalid(25137)),
+                    _ => panic!(),
+                }
+            }
+        }
+    }
+
+    let mut score = 0;
+
+    while let Some(c) = stack.pop() {
+        let points = match c {
+            b'(' => 1,
+            b'[' => 2,
+            b'{' => 3,
+            b'<' => 4,
+            _ => unreachable!(),
+        };
+        score = checked::add(checked::mul(score, 5)?, points)?;
+    }
+
+    Ok(Validate::Complete(score))
+}
+
+fn parse_lines(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut lines = Vec::new();
+    for line in parse::lines(input) {
+        if let Some(i) = line.text.find(|c| !"()[]{}<>".contains(c)) {
+            return Err(line.error(&line.text[i..], "bracket"));
+        }
+        lines.push(line.text.to_owned());
+    }
+    Ok(lines)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<u64, Error> {
+        let mut sum = 0;
+        for line in lines {
+            if let Validate::Invalid(n) = validate(line)? {
+                sum = checked::add(sum, n)?;
+            }
+        }
+        Ok(sum)
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<u64, Error> {
+        let mut scores = Vec::new();
+        for line in lines {
+            if let Validate::Complete(score) = validate(line)? {
+                scores.push(score);
+            }
+        }
+        scores.sort();
+        assert_eq!(1, scores.len() % 2);
+        Ok(scores[scores.len() / 2])
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::grid::Edges;
+use crate::grid::Grid;
+use crate::grid::Pos;
+use crate::image::Frames;
+use crate::image::Image;
+use crate::image::Rgb;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Animate;
+use crate::Error;
+use crate::Simulate;
+use crate::Solution;
+
+#[derive(Clone)]
+pub struct Octopuses {
+    grid: Grid<u32>,
+}
+
+impl fmt::Display for Octopuses {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Octopuses {
+    fn parse(content: &str) -> Result<Octopuses, ParseError> {
+        let grid = Grid::from_digits(content)?;
+        Ok(Octopuses { grid })
+    }
+
+    fn inc1(&mut self) {
+        for c in self.grid.values_mut() {
+            *c += 1;
+        }
+    }
+
+    fn reset0(&mut self) {
+        for c in self.grid.values_mut() {
+            if *c > 9 {
+                *c = 0;
+            }
+        }
+    }
+
+    fn check_flash(&mut self, pos: Pos, flashes: &mut u64, flashed: &mut HashSet<Pos>) {
+        if flashed.contains(&pos) {
+            return;
+        }
+
+        if self.grid[pos] <= 9 {
+            return;
+        }
+
+        *flashes += 1;
+        flashed.insert(pos);
+        let neighbours: Vec<Pos> = self.grid.neighbours8(pos, Edges::Bounded).collect();
+        for n in neighbours {
+            self.grid[n] += 1;
+            self.check_flash(n, flashes, flashed);
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.grid.len()
+    }
+
+    /// Octopuses which just flashed are white, others get brighter as
+    /// their energy grows.
+    fn image(&self) -> Image {
+        Image::from_grid(&self.grid, |&energy| match energy {
+            0 => Rgb::WHITE,
+            e => Rgb::gray(e as u8 * 20),
+        })
+    }
+
+    fn step(&mut self) -> u64 {
+        let mut flashes = 0;
+        let mut flashed = HashSet::new();
+        self.inc1();
+        let positions: Vec<Pos> = self.grid.positions().collect();
+        for pos in positions {
+            self.check_flash(pos, &mut flashes, &mut flashed);
+        }
+        self.reset0();
+        flashes
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Octopuses;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Octopuses, Error> {
+        Ok(Octopuses::parse(input)?)
+    }
+
+    fn part1(octopuses: &Octopuses) -> Result<u64, Error> {
+        let mut oct = octopuses.clone();
+        let mut total_flashes = 0;
+        for step in 0..100 {
+            trace!("Step {}", step);
+            trace!("{}", oct.grid);
+
+            total_flashes += oct.step();
+        }
+        Ok(total_flashes)
+    }
+
+    fn part2(octopuses: &Octopuses) -> Result<u64, Error> {
+        let mut oct = octopuses.clone();
+        for step in 1.. {
+            let flashes = oct.step();
+            if flashes == oct.count() as u64 {
+                return Ok(step);
+            }
+        }
+        unreachable!()
+    }
+}
+
+impl Animate for Day11 {
+    /// Steps until all octopuses flash at once.
+    fn animate(octopuses: &Octopuses, frames: &mut Frames) -> Result<(), Error> {
+        let mut oct = octopuses.clone();
+        frames.push(&oct.image())?;
+        loop {
+            let flashes = oct.step();
+            frames.push(&oct.image())?;
+            if flashes == oct.count() as u64 {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl Simulate for Day11 {
+    type State = Octopuses;
+
+    fn start(octopuses: &Octopuses) -> Octopuses {
+        octopuses.clone()
+    }
+
+    fn step(_input: &Octopuses, octopuses: &Octopuses) -> Result<Option<Octopuses>, Error> {
+        let mut octopuses = octopuses.clone();
+        octopuses.step();
+        Ok(Some(octopuses))
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Error;
+use crate::Solution;
+
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+struct Node {
+    name: String,
+}
+
+impl Node {
+    fn parse(line: &Line, name: &str) -> Result<Node, ParseError> {
+        if name.is_empty()
+            || !(name.bytes().all(|b| b.is_ascii_lowercase())
+                || name.bytes().all(|b| b.is_ascii_uppercase()))
+        {
+            return Err(line.error(name, "all lowercase or all uppercase cave name"));
+        }
+        Ok(Node {
+            name: name.to_owned(),
+        })
+    }
+
+    fn is_end(&self) -> bool {
+        self.name == "end"
+    }
+
+    fn is_start(&self) -> bool {
+        self.name == "start"
+    }
+
+    fn is_small(&self) -> bool {
+        self.name.bytes().next().unwrap().is_ascii_lowercase()
+    }
+}
+
+pub struct Graph {
+    edges: HashMap<Node, HashSet<Node>>,
+}
+
+impl Graph {
+    fn parse(content: &str) -> Result<Graph, ParseError> {
+        let mut edges = HashMap::<Node, HashSet<Node>>::new();
+        let mut lines = parse::lines(content);
+        for line in &mut lines {
+            let (a, b) = line.split_once(line.text, "-")?;
+            let a = Node::parse(&line, a)?;
+            let b = Node::parse(&line, b)?;
+            edges.entry(a.clone()).or_default().insert(b.clone());
+            edges.entry(b).or_default().insert(a);
+        }
+        for name in ["start", "end"] {
+            let node = Node {
+                name: name.to_owned(),
+            };
+            if !edges.contains_key(&node) {
+                return Err(lines.error_after_last(&format!("connection to `{}`", name)));
+            }
+        }
+        Ok(Graph { edges })
+    }
+}
+
+#[derive(Clone, Default)]
+struct Path<'a> {
+    nodes: Vec<&'a Node>,
+    has_2_small: Option<&'a Node>,
+}
+
+impl<'a> Path<'a> {
+    fn last(&self) -> &'a Node {
+        self.nodes.last().unwrap()
+    }
+
+    fn push(&mut self, node: &'a Node) {
+        if self.nodes.len() == 20 {
+            trace!("long path: {:?}", self.nodes);
+        }
+        if node.is_small() && self.nodes.contains(&node) {
+            assert!(self.has_2_small.is_none());
+            self.has_2_small = Some(node);
+        }
+        self.nodes.push(node);
+    }
+}
+
+fn find_all_paths_1(graph: &Graph, from: &Path) -> u64 {
+    if from.last().is_end() {
+        return 1;
+    }
+
+    let nexts = graph.edges.get(from.last()).unwrap();
+    let mut count = 0;
+    for next in nexts {
+        if next.is_start() {
+            continue;
+        }
+        if next.is_small() && from.nodes.contains(&next) {
+            continue;
+        }
+        let mut new_from = from.clone();
+        new_from.push(next);
+        count += find_all_paths_1(graph, &new_from);
+    }
+    count
+}
+
+fn find_all_paths_2(graph: &Graph, from: &Path) -> u64 {
+    if from.last().is_end() {
+        return 1;
+    }
+
+    let nexts = graph.edges.get(from.last()).unwrap();
+    let mut count = 0;
+    for next in nexts {
+        if next.is_start() {
+            continue;
+        }
+        if next.is_small() {
+            if let Some(node) = from.has_2_small {
+                if node == next || from.nodes.contains(&next) {
+                    continue;
+                }
+            }
+        }
+        let mut new_from = from.clone();
+        new_from.push(next);
+        count += find_all_paths_2(graph, &new_from);
+    }
+    count
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Graph;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Graph, Error> {
+        Ok(Graph::parse(input)?)
+    }
+
+    fn part1(graph: &Graph) -> Result<u64, Error> {
+        let mut path = Path::default();
+        let start = Node {
+            name: "start".to_owned(),
+        };
+        path.push(&start);
+        let count = find_all_paths_1(graph, &path);
+        Ok(count)
+    }
+
+    fn part2(graph: &Graph) -> Result<u64, Error> {
+        let mut path = Path::default();
+        let start = Node {
+            name: "start".to_owned(),
+        };
+        path.push(&start);
+        let count = find_all_paths_2(graph, &path);
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_and_end() {
+        let error = |input| Graph::parse(input).err().unwrap();
+        assert_eq!(ParseError::new(1, 1, "connection to `start`"), error(""));
+        assert_eq!(
+            ParseError::new(2, 1, "connection to `start`"),
+            error("a-b\n")
+        );
+        assert_eq!(
+            ParseError::new(2, 1, "connection to `end`"),
+            error("start-A\n")
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::image::Frames;
+use crate::image::Image;
+use crate::image::Rgb;
+use crate::parse;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Animate;
+use crate::Error;
+use crate::Solution;
+
+#[derive(Copy, Clone, Debug)]
+enum Axis {
+    X,
+    Y,
+}
+
+#[derive(Clone)]
+pub struct Dots {
+    dots: Vec<(u32, u32)>,
+}
+
+impl fmt::Display for Dots {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let max_x = self.dots.iter().map(|(x, _)| *x).max().unwrap();
+        let max_y = self.dots.iter().map(|(_, y)| *y).max().unwrap();
+        for y in 0..=max_y {
+            writeln!(f)?;
+            for x in 0..=max_x {
+                if self.dots.contains(&(x, y)) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Dots {
+    fn fold(&mut self, axis: Axis, v: u32) {
+        for dot in &mut self.dots {
+            let x = match axis {
+                Axis::X => &mut dot.0,
+                Axis::Y => &mut dot.1,
+            };
+            assert!(*x != v);
+            if *x < v {
+                continue;
+            }
+            *x = v - (*x - v);
+        }
+    }
+
+    fn image(&self) -> Image {
+        let max_x = self.dots.iter().map(|(x, _)| *x).max().unwrap() as usize;
+        let max_y = self.dots.iter().map(|(_, y)| *y).max().unwrap() as usize;
+        let mut grid = Grid::new(max_y + 1, max_x + 1, false);
+        for &(x, y) in &self.dots {
+            grid[(y as usize, x as usize)] = true;
+        }
+        Image::from_grid(&grid, |&dot| if dot { Rgb::WHITE } else { Rgb::BLACK })
+    }
+
+    fn count_unique(&self) -> usize {
+        HashSet::<&(_, _)>::from_iter(&self.dots).len()
+    }
+}
+
+pub struct Input {
+    dots: Dots,
+    folds: Vec<(Axis, u32)>,
+}
+
+impl Input {
+    fn parse(content: &str) -> Result<Input, ParseError> {
+        let mut dots = Vec::new();
+        let mut folds = Vec::new();
+        let mut lines = parse::lines(content);
+        loop {
+            let line = lines.next_line("dot or empty line")?;
+            if line.text.is_empty() {
+                break;
+            }
+            let (x, y) = line.split_once(line.text, ",")?;
+            let x = line.parse(x, "x coordinate")?;
+            let y = line.parse(y, "y coordinate")?;
+            dots.push((x, y));
+        }
+        if dots.is_empty() {
+            return Err(ParseError::new(1, 1, "dot"));
+        }
+        let dots = Dots { dots };
+        for line in &mut lines {
+            let fold = line.strip_prefix(line.text, "fold along ")?;
+            let (axis, value) = line.split_once(fold, "=")?;
+            let axis = match axis {
+                "x" => Axis::X,
+                "y" => Axis::Y,
+                _ => return Err(line.error(axis, "`x` or `y`")),
+            };
+            let value = line.parse(value, "fold coordinate")?;
+            folds.push((axis, value));
+        }
+        if folds.is_empty() {
+            return Err(lines.error_after_last("fold"));
+        }
+        Ok(Input { dots, folds })
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = Dots;
+
+    fn parse(input: &str) -> Result<Input, Error> {
+        Ok(Input::parse(input)?)
+    }
+
+    fn part1(input: &Input) -> Result<usize, Error> {
+        let mut dots = input.dots.clone();
+        let fold = input.folds[0];
+        dots.fold(fold.0, fold.1);
+        Ok(dots.count_unique())
+    }
+
+    fn part2(input: &Input) -> Result<Dots, Error> {
+        let mut dots = input.dots.clone();
+        trace!("{}", dots);
+        for fold in &input.folds {
+            dots.fold(fold.0, fold.1);
+            trace!("{}", dots);
+        }
+        Ok(dots)
+    }
+}
+
+impl Animate for Day13 {
+    /// Paper before and after every fold.
+    fn animate(input: &Input, frames: &mut Frames) -> Result<(), Error> {
+        let mut dots = input.dots.clone();
+        frames.push(&dots.image())?;
+        for fold in &input.folds {
+            dots.fold(fold.0, fold.1);
+            frames.push(&dots.image())?;
+        }
+        Ok(())
+    }
+}
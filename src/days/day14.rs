@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::checked;
+use crate::checked::Overflow;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Error;
+use crate::Solution;
+
+struct Rule {
+    input: (char, char),
+    output: char,
+}
+
+impl Rule {
+    fn parse(line: &Line) -> Result<Rule, ParseError> {
+        let (input, output) = line.split_once(line.text, " -> ")?;
+        let input = match input.chars().collect::<Vec<_>>()[..] {
+            [input_0, input_1] => (input_0, input_1),
+            _ => return Err(line.error(input, "pair of elements")),
+        };
+        let output = match output.chars().collect::<Vec<_>>()[..] {
+            [output] => output,
+            _ => return Err(line.error(output, "single element")),
+        };
+        Ok(Rule { input, output })
+    }
+}
+
+pub struct Input {
+    template: String,
+    rules_map: HashMap<(char, char), char>,
+}
+
+impl Input {
+    fn parse(content: &str) -> Result<Input, ParseError> {
+        let mut lines = parse::lines(content);
+        let template = lines.next_line("template")?.text.to_owned();
+        lines.next_line("empty line")?.expect("")?;
+        let mut rules_map = HashMap::new();
+        for line in lines {
+            let rule = Rule::parse(&line)?;
+            if rules_map.insert(rule.input, rule.output).is_some() {
+                return Err(line.error(line.text, "no duplicate rules"));
+            }
+        }
+
+        Ok(Input {
+            template,
+            rules_map,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Key {
+    bounds: (char, char),
+    depth: usize,
+}
+
+#[derive(Default)]
+struct LetterStats {
+    counts: HashMap<char, usize>,
+}
+
+impl LetterStats {
+    fn add(&mut self, c: char) {
+        *self.counts.entry(c).or_insert(0) += 1;
+    }
+
+    fn add_all(&mut self, s: &LetterStats) -> Result<(), Overflow> {
+        for (c, count) in s.counts.iter() {
+            let total = self.counts.entry(*c).or_insert(0);
+            *total = checked::add(*total, *count)?;
+        }
+        Ok(())
+    }
+}
+
+fn find_between<'a>(
+    rule_map: &HashMap<(char, char), char>,
+    key: &Key,
+    memo: &'a mut HashMap<Key, LetterStats>,
+) -> Result<&'a LetterStats, Overflow> {
+    trace!("find_between {:?}", key);
+    if let Some(_stats) = memo.get(key) {
+        return Ok(memo.get(key).unwrap());
+    }
+    if key.depth == 0 {
+        memo.insert(*key, LetterStats::default());
+        return Ok(memo.get(key).unwrap());
+    }
+    let mut stats = LetterStats::default();
+    if let Some(&x) = rule_map.get(&key.bounds) {
+        stats.add(x);
+        stats.add_all(find_between(
+            rule_map,
+            &Key {
+                bounds: (key.bounds.0, x),
+                depth: key.depth - 1,
+            },
+            memo,
+        )?)?;
+        stats.add_all(find_between(
+            rule_map,
+            &Key {
+                bounds: (x, key.bounds.1),
+                depth: key.depth - 1,
+            },
+            memo,
+        )?)?;
+    }
+    memo.insert(*key, stats);
+    Ok(memo.get(key).unwrap())
+}
+
+fn run(input: &Input, depth: usize) -> Result<usize, Overflow> {
+    let template = &input.template;
+
+    let mut memo: HashMap<Key, LetterStats> = HashMap::new();
+
+    let mut letter_stats = LetterStats::default();
+    for c in template.chars() {
+        letter_stats.add(c);
+    }
+    for (a, b) in template.chars().zip(template.chars().skip(1)) {
+        let key = Key {
+            bounds: (a, b),
+            depth,
+        };
+        let stats = find_between(&input.rules_map, &key, &mut memo)?;
+        letter_stats.add_all(stats)?;
+    }
+    let min = letter_stats
+        .counts
+        .iter()
+        .min_by_key(|(_, c)| *c)
+        .unwrap()
+        .1;
+    let max = letter_stats
+        .counts
+        .iter()
+        .max_by_key(|(_, c)| *c)
+        .unwrap()
+        .1;
+    Ok(max - min)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Input, Error> {
+        Ok(Input::parse(input)?)
+    }
+
+    fn part1(input: &Input) -> Result<usize, Error> {
+        Ok(run(input, 10)?)
+    }
+
+    fn part2(input: &Input) -> Result<usize, Error> {
+        Ok(run(input, 40)?)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::grid::Edges;
+use crate::grid::Grid;
+use crate::grid::Pos;
+use crate::parse;
+use crate::parse::ParseError;
+use crate::search;
+use crate::trace;
+use crate::Error;
+use crate::Solution;
+
+pub struct Map {
+    grid: Grid<u8>,
+}
+
+impl Map {
+    fn parse(content: &str) -> Result<Map, ParseError> {
+        // Risk levels are at least 1, the A* heuristic relies on it.
+        let rows = parse::char_grid(content, "risk level 1-9", |c| match c {
+            '1'..='9' => Some(c as u8 - b'0'),
+            _ => None,
+        })?;
+        Ok(Map {
+            grid: Grid::from_rows(rows),
+        })
+    }
+
+    fn mult_5(&self) -> Map {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let mut grid = Grid::new(rows * 5, cols * 5, 0);
+        for i in 0..5 {
+            for j in 0..5 {
+                for ((r, c), risk) in self.grid.iter() {
+                    grid[(i * rows + r, j * cols + c)] = (risk + i as u8 + j as u8 - 1) % 9 + 1;
+                }
+            }
+        }
+        Map { grid }
+    }
+}
+
+fn lowest_total_risk(map: &Map) -> u64 {
+    let grid = &map.grid;
+    let goal = (grid.rows() - 1, grid.cols() - 1);
+    let path = search::astar(
+        (0, 0),
+        |&pos| {
+            grid.neighbours4(pos, Edges::Bounded)
+                .map(|n| (n, grid[n] as u64))
+                .collect::<Vec<_>>()
+        },
+        // Each step costs at least 1.
+        |&(r, c): &Pos| ((goal.0 - r) + (goal.1 - c)) as u64,
+        |&pos| pos == goal,
+    );
+    path.unwrap().cost
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Map, Error> {
+        Ok(Map::parse(input)?)
+    }
+
+    fn part1(map: &Map) -> Result<u64, Error> {
+        Ok(lowest_total_risk(map))
+    }
+
+    fn part2(map: &Map) -> Result<u64, Error> {
+        let map = map.mult_5();
+        trace!("{}", map.grid);
+        Ok(lowest_total_risk(&map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_risk() {
+        assert_eq!(
+            ParseError::new(1, 2, "risk level 1-9"),
+            Map::parse("10\n11\n").err().unwrap()
+        );
+    }
+}
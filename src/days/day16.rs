@@ -0,0 +1,418 @@
+use std::fmt;
+
+use crate::checked;
+use crate::checked::Overflow;
+use crate::debug;
+use crate::parse;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
+
+struct Bits {
+    bits: Vec<bool>,
+}
+
+impl fmt::Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for bit in self.bits.iter() {
+            write!(f, "{}", if *bit { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+impl Bits {
+    fn parse(input: &str) -> Result<Bits, ParseError> {
+        let line = parse::lines(input).next_line("hex digits")?;
+        let mut bits = Vec::new();
+        for (i, c) in line.text.trim_end().char_indices() {
+            let v = match c.to_digit(16) {
+                Some(v) => v,
+                None => return Err(line.error(&line.text[i..], "hex digit")),
+            };
+            for i in (0..4).rev() {
+                bits.push((v >> i) & 1 != 0);
+            }
+        }
+        Ok(Bits { bits })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum PacketContent {
+    Literal(u64),
+    Sub(Vec<Packet>),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Packet {
+    version: u32,
+    type_id: TypeId,
+    content: PacketContent,
+}
+
+impl Packet {
+    fn version_sum(&self) -> Result<u32, Overflow> {
+        match &self.content {
+            PacketContent::Literal(_) => Ok(self.version),
+            PacketContent::Sub(packets) => packets
+                .iter()
+                .try_fold(self.version, |sum, p| checked::add(sum, p.version_sum()?)),
+        }
+    }
+
+    fn eval(&self) -> Result<u64, Overflow> {
+        match &self.content {
+            PacketContent::Literal(literal) => {
+                assert_eq!(TypeId::Literal, self.type_id);
+                Ok(*literal)
+            }
+            PacketContent::Sub(packets) => {
+                let values = packets
+                    .iter()
+                    .map(|p| p.eval())
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(match self.type_id {
+                    TypeId::Sum => checked::sum(values)?,
+                    TypeId::Product => checked::product(values)?,
+                    TypeId::Min => values.into_iter().min().unwrap(),
+                    TypeId::Max => values.into_iter().max().unwrap(),
+                    TypeId::Literal => panic!(),
+                    TypeId::Gt => match values.as_slice() {
+                        [v0, v1] => (v0 > v1) as u64,
+                        _ => panic!(),
+                    },
+                    TypeId::Lt => match values.as_slice() {
+                        [v0, v1] => (v0 < v1) as u64,
+                        _ => panic!(),
+                    },
+                    TypeId::Eq => match values.as_slice() {
+                        [v0, v1] => (v0 == v1) as u64,
+                        _ => panic!(),
+                    },
+                })
+            }
+        }
+    }
+}
+
+enum LenTypeId {
+    Bits,
+    Packets,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Len {
+    Bits(u32),
+    Packets(u32),
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum TypeId {
+    Sum,     // 0
+    Product, // 1
+    Min,     // 2
+    Max,     // 3
+    Literal, // 4
+    Gt,      // 5
+    Lt,      // 6
+    Eq,      // 7
+}
+
+struct Parser<'a> {
+    bits: &'a Bits,
+    pos: usize,
+    // Position of `bits` in the whole transmission.
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn eof(&self) -> bool {
+        self.pos == self.bits.bits.len()
+    }
+
+    // Errors point to the hex digit containing the current bit.
+    fn error(&self, expected: &str) -> ParseError {
+        ParseError::new(1, (self.offset + self.pos) / 4 + 1, expected)
+    }
+
+    fn next_bit(&mut self) -> Result<bool, ParseError> {
+        match self.bits.bits.get(self.pos) {
+            Some(&bit) => {
+                self.pos += 1;
+                Ok(bit)
+            }
+            None => Err(self.error("more bits")),
+        }
+    }
+
+    fn next_n_bits_be(&mut self, n: u32) -> Result<u64, ParseError> {
+        let mut v = 0;
+        for _ in 0..n {
+            v <<= 1;
+            v |= self.next_bit()? as u64;
+        }
+        Ok(v)
+    }
+
+    fn next_version(&mut self) -> Result<u32, ParseError> {
+        Ok(self.next_n_bits_be(3)? as u32)
+    }
+
+    fn next_type_id(&mut self) -> Result<TypeId, ParseError> {
+        Ok(match self.next_n_bits_be(3)? {
+            0 => TypeId::Sum,
+            1 => TypeId::Product,
+            2 => TypeId::Min,
+            3 => TypeId::Max,
+            4 => TypeId::Literal,
+            5 => TypeId::Gt,
+            6 => TypeId::Lt,
+            7 => TypeId::Eq,
+            _ => unreachable!(),
+        })
+    }
+
+    fn next_len_type_id(&mut self) -> Result<LenTypeId, ParseError> {
+        Ok(match self.next_bit()? {
+            false => LenTypeId::Bits,
+            true => LenTypeId::Packets,
+        })
+    }
+
+    fn next_len(&mut self) -> Result<Len, ParseError> {
+        Ok(match self.next_len_type_id()? {
+            LenTypeId::Bits => Len::Bits(self.next_n_bits_be(15)? as u32),
+            LenTypeId::Packets => Len::Packets(self.next_n_bits_be(11)? as u32),
+        })
+    }
+
+    fn next_bits(&mut self, count: u32) -> Result<Bits, ParseError> {
+        let mut bits = Vec::new();
+        for _ in 0..count {
+            bits.push(self.next_bit()?);
+        }
+        Ok(Bits { bits })
+    }
+
+    fn next_varint(&mut self) -> Result<u64, ParseError> {
+        let mut r = 0;
+        loop {
+            let group = self.next_n_bits_be(5)?;
+            let last = (group & 0b10000) == 0;
+            let group = group & 0b01111;
+            // Overflowing literal is malformed input rather than overflow
+            // in the solver, so it is checked also without checked mode.
+            if r >> 60 != 0 {
+                return Err(self.error("literal fitting 64 bits"));
+            }
+            r = (r << 4) | group;
+            if last {
+                return Ok(r);
+            }
+        }
+    }
+
+    fn trailing_zeros(&mut self) -> Result<(), ParseError> {
+        // assert!(self.bits.bits.len() - self.pos < 4);
+        while !self.eof() {
+            if self.next_bit()? {
+                self.pos -= 1;
+                return Err(self.error("zero padding"));
+            }
+        }
+        Ok(())
+    }
+
+    fn next_packet_content(&mut self, type_id: TypeId) -> Result<PacketContent, ParseError> {
+        if type_id == TypeId::Literal {
+            let literal = self.next_varint()?;
+            Ok(PacketContent::Literal(literal))
+        } else {
+            let start = self.pos;
+            let len = self.next_len()?;
+            let packets = match len {
+                Len::Bits(bits) => {
+                    let offset = self.offset + self.pos;
+                    let bits = self.next_bits(bits)?;
+                    let mut parser = Parser {
+                        bits: &bits,
+                        pos: 0,
+                        offset,
+                    };
+                    let mut packets = Vec::new();
+                    while !parser.eof() {
+                        let packet = parser.next_packet()?;
+                        packets.push(packet);
+                    }
+                    packets
+                }
+                Len::Packets(packet_count) => {
+                    let mut packets = Vec::new();
+                    for _ in 0..packet_count {
+                        let packet = self.next_packet()?;
+                        packets.push(packet);
+                    }
+                    packets
+                }
+            };
+            let valid = match type_id {
+                TypeId::Gt | TypeId::Lt | TypeId::Eq => packets.len() == 2,
+                _ => !packets.is_empty(),
+            };
+            if !valid {
+                self.pos = start;
+                return Err(self.error(match type_id {
+                    TypeId::Gt | TypeId::Lt | TypeId::Eq => "two sub-packets",
+                    _ => "at least one sub-packet",
+                }));
+            }
+            Ok(PacketContent::Sub(packets))
+        }
+    }
+
+    fn next_packet(&mut self) -> Result<Packet, ParseError> {
+        let version = self.next_version()?;
+        let type_id = self.next_type_id()?;
+        let content = self.next_packet_content(type_id)?;
+        Ok(Packet {
+            version,
+            type_id,
+            content,
+        })
+    }
+
+    fn parse_bits(bits: &Bits) -> Result<Packet, ParseError> {
+        let mut parser = Parser {
+            bits,
+            pos: 0,
+            offset: 0,
+        };
+        let packet = parser.next_packet()?;
+        parser.trailing_zeros()?;
+        Ok(packet)
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Packet, Error> {
+        Ok(Parser::parse_bits(&Bits::parse(input)?)?)
+    }
+
+    fn part1(packet: &Packet) -> Result<u32, Error> {
+        Ok(packet.version_sum()?)
+    }
+
+    fn part2(packet: &Packet) -> Result<u64, Error> {
+        debug!("{:?}", packet);
+        Ok(packet.eval()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        let bits = Bits::parse("D2FE28").unwrap();
+        assert_eq!("110100101111111000101000", bits.to_string());
+        let packet = Parser::parse_bits(&bits).unwrap();
+        assert_eq!(
+            Packet {
+                version: 6,
+                type_id: TypeId::Literal,
+                content: PacketContent::Literal(2021),
+            },
+            packet
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let bits = Bits::parse("38006F45291200").unwrap();
+        assert_eq!(
+            "00111000000000000110111101000101001010010001001000000000",
+            bits.to_string()
+        );
+        let packet = Parser::parse_bits(&bits).unwrap();
+        assert_eq!(
+            Packet {
+                version: 1,
+                type_id: TypeId::Lt,
+                content: PacketContent::Sub(vec![
+                    Packet {
+                        version: 6,
+                        type_id: TypeId::Literal,
+                        content: PacketContent::Literal(10),
+                    },
+                    Packet {
+                        version: 2,
+                        type_id: TypeId::Literal,
+                        content: PacketContent::Literal(20),
+                    },
+                ]),
+            },
+            packet
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let bits = Bits::parse("EE00D40C823060").unwrap();
+        assert_eq!(
+            "11101110000000001101010000001100100000100011000001100000",
+            bits.to_string()
+        );
+        let packet = Parser::parse_bits(&bits).unwrap();
+        assert_eq!(
+            Packet {
+                version: 7,
+                type_id: TypeId::Max,
+                content: PacketContent::Sub(vec![
+                    Packet {
+                        version: 2,
+                        type_id: TypeId::Literal,
+                        content: PacketContent::Literal(1),
+                    },
+                    Packet {
+                        version: 4,
+                        type_id: TypeId::Literal,
+                        content: PacketContent::Literal(2),
+                    },
+                    Packet {
+                        version: 1,
+                        type_id: TypeId::Literal,
+                        content: PacketContent::Literal(3),
+                    },
+                ]),
+            },
+            packet
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let bits = Bits::parse("D2FE").unwrap();
+        assert_eq!(
+            ParseError::new(1, 5, "more bits"),
+            Parser::parse_bits(&bits).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn long_literal() {
+        // Literal of 17 groups of ones, one group more than fits 64 bits.
+        let bits = Bits::parse("13FFFFFFFFFFFFFFFFFFFDE").unwrap();
+        assert_eq!(
+            ParseError::new(1, 23, "literal fitting 64 bits"),
+            Parser::parse_bits(&bits).unwrap_err()
+        );
+    }
+}
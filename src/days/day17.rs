@@ -0,0 +1,101 @@
+use std::cmp;
+use std::collections::HashSet;
+
+use crate::debug;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Error;
+use crate::Solution;
+
+pub struct Input {
+    x_range: (i64, i64),
+    y_range: (i64, i64),
+}
+
+impl Input {
+    fn parse_range(line: &Line, s: &str, axis: &str) -> Result<(i64, i64), ParseError> {
+        let s = line.strip_prefix(s, axis)?;
+        let s = line.strip_prefix(s, "=")?;
+        let (from, to) = line.split_once(s, "..")?;
+        Ok((line.parse(from, "number")?, line.parse(to, "number")?))
+    }
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        let line = parse::lines(input).next_line("target area")?;
+        let input = line.strip_prefix(line.text.trim_end(), "target area: ")?;
+        let (x, y) = line.split_once(input, ", ")?;
+        Ok(Input {
+            x_range: Input::parse_range(&line, x, "x")?,
+            y_range: Input::parse_range(&line, y, "y")?,
+        })
+    }
+}
+
+fn run(input: &Input) -> (i64, usize) {
+    let mut total_max_y = i64::MIN;
+    let mut uniq_init_velocities = HashSet::new();
+
+    for v_x_init in 1..=input.x_range.1 {
+        debug!("v_x: {}", v_x_init);
+        for v_y_init in input.y_range.0..10000 {
+            trace!("v_y: {}", v_y_init);
+            let mut v_x = v_x_init;
+            let mut v_y = v_y_init;
+
+            let mut x = 0;
+            let mut y = 0;
+
+            let mut max_y = y;
+
+            while x <= input.x_range.1 {
+                trace!("{} {}", x, y);
+
+                max_y = cmp::max(max_y, y);
+
+                if x >= input.x_range.0
+                    && x <= input.x_range.1
+                    && y >= input.y_range.0
+                    && y <= input.y_range.1
+                {
+                    total_max_y = cmp::max(total_max_y, max_y);
+                    uniq_init_velocities.insert((v_x_init, v_y_init));
+                    break;
+                }
+
+                if y < input.y_range.0 {
+                    break;
+                }
+
+                x += v_x;
+                y += v_y;
+                v_x -= v_x.signum();
+                v_y -= 1;
+            }
+        }
+    }
+    (total_max_y, uniq_init_velocities.len())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Input;
+    type Answer1 = i64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Input, Error> {
+        Ok(Input::parse(input)?)
+    }
+
+    fn part1(input: &Input) -> Result<i64, Error> {
+        let (total_max_y, _) = run(input);
+        Ok(total_max_y)
+    }
+
+    fn part2(input: &Input) -> Result<usize, Error> {
+        let (_, uniq_init_velocities) = run(input);
+        Ok(uniq_init_velocities)
+    }
+}
@@ -0,0 +1,514 @@
+use std::cmp;
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::checked;
+use crate::checked::Overflow;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Error;
+use crate::Simulate;
+use crate::Solution;
+
+#[derive(PartialEq, Debug, Clone)]
+enum Elem {
+    Number(u64),
+    Pair(Box<Pair>),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Pair {
+    elems: [Elem; 2],
+}
+
+impl fmt::Display for Elem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Elem::Number(n) => write!(f, "{}", n),
+            Elem::Pair(p) => write!(f, "{}", p),
+        }
+    }
+}
+
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{},{}]", self.elems[0], self.elems[1])
+    }
+}
+
+impl Elem {
+    fn number(&self) -> u64 {
+        match self {
+            Elem::Number(n) => *n,
+            Elem::Pair(..) => panic!(),
+        }
+    }
+
+    fn most_value(&mut self, index: usize) -> &mut u64 {
+        match self {
+            Elem::Number(n) => n,
+            Elem::Pair(p) => p.most_value(index),
+        }
+    }
+
+    fn explode_impl(
+        &mut self,
+        outer: u32,
+        left: Option<&mut u64>,
+        right: Option<&mut u64>,
+    ) -> Result<bool, Overflow> {
+        trace!("explode_impl {} {}", self, outer);
+        match self {
+            Elem::Number(_) => Ok(false),
+            Elem::Pair(pair) if outer == 3 => {
+                let pair = pair.two_numbers();
+                if let Some(left) = left {
+                    *left = checked::add(*left, pair[0])?;
+                }
+                if let Some(right) = right {
+                    *right = checked::add(*right, pair[1])?;
+                }
+                *self = Elem::Number(0);
+                Ok(true)
+            }
+            Elem::Pair(pair) => pair.explode_impl(outer + 1, left, right),
+        }
+    }
+
+    fn split(&mut self) -> bool {
+        match self {
+            Elem::Number(n) if *n >= 10 => {
+                *self = Elem::Pair(Box::new(Pair {
+                    elems: [Elem::Number(*n / 2), Elem::Number(n.div_ceil(2))],
+                }));
+                true
+            }
+            Elem::Number(_) => false,
+            Elem::Pair(p) => p.split(),
+        }
+    }
+
+    fn magnitude(&self) -> Result<u64, Overflow> {
+        match self {
+            Elem::Number(n) => Ok(*n),
+            Elem::Pair(p) => p.magnitude(),
+        }
+    }
+}
+
+impl Pair {
+    fn parse(line: &Line) -> Result<Pair, ParseError> {
+        Pair::parse_with_depth(line, MAX_DEPTH)
+    }
+
+    fn parse_with_depth(line: &Line, max_depth: usize) -> Result<Pair, ParseError> {
+        let mut parser = Parser {
+            line,
+            pos: 0,
+            depth: 0,
+            max_depth,
+        };
+        let pair = parser.next_pair()?;
+        if !parser.eof() {
+            return Err(parser.error("end of line"));
+        }
+        Ok(pair)
+    }
+
+    fn two_numbers(&self) -> [u64; 2] {
+        [self.elems[0].number(), self.elems[1].number()]
+    }
+
+    fn most_value(&mut self, index: usize) -> &mut u64 {
+        self.elems[index].most_value(index)
+    }
+
+    fn explode_impl(
+        &mut self,
+        outer: u32,
+        left: Option<&mut u64>,
+        right: Option<&mut u64>,
+    ) -> Result<bool, Overflow> {
+        let [e_0, e_1] = &mut self.elems;
+        if e_0.explode_impl(outer, left, Some(e_1.most_value(0)))? {
+            return Ok(true);
+        }
+        if e_1.explode_impl(outer, Some(e_0.most_value(1)), right)? {
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn explode(&mut self) -> Result<bool, Overflow> {
+        self.explode_impl(0, None, None)
+    }
+
+    fn split(&mut self) -> bool {
+        if self.elems[0].split() {
+            return true;
+        }
+        if self.elems[1].split() {
+            return true;
+        }
+        false
+    }
+
+    /// Sum before reduction.
+    fn concat(a: &Pair, b: &Pair) -> Pair {
+        Pair {
+            elems: [
+                Elem::Pair(Box::new(a.clone())),
+                Elem::Pair(Box::new(b.clone())),
+            ],
+        }
+    }
+
+    /// Do one explode or split, return which one, `None` if reduced.
+    fn reduce_action(&mut self) -> Result<Option<&'static str>, Overflow> {
+        Ok(if self.explode()? {
+            Some("explode")
+        } else if self.split() {
+            Some("split")
+        } else {
+            None
+        })
+    }
+
+    fn add(a: &Pair, b: &Pair) -> Result<Pair, Overflow> {
+        let mut sum = Pair::concat(a, b);
+        while sum.reduce_action()?.is_some() {}
+        Ok(sum)
+    }
+
+    fn magnitude(&self) -> Result<u64, Overflow> {
+        let [l, r] = &self.elems;
+        checked::add(
+            checked::mul(l.magnitude()?, 3)?,
+            checked::mul(r.magnitude()?, 2)?,
+        )
+    }
+}
+
+/// Snailfish numbers in the input are reduced.
+const MAX_DEPTH: usize = 4;
+
+struct Parser<'a> {
+    line: &'a Line<'a>,
+    pos: usize,
+    /// Number of pairs the parser is in.
+    depth: usize,
+    max_depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn eof(&self) -> bool {
+        self.pos == self.line.text.len()
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        self.line.error(&self.line.text[self.pos..], expected)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.line.text[self.pos..].chars().next()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() != Some(expected) {
+            return Err(self.error(&format!("`{}`", expected)));
+        }
+        self.pos += expected.len_utf8();
+        Ok(())
+    }
+
+    fn next_elem(&mut self) -> Result<Elem, ParseError> {
+        if self.peek() == Some('[') {
+            Ok(Elem::Pair(Box::new(self.next_pair()?)))
+        } else {
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.pos += 1;
+            }
+            if start == self.pos {
+                return Err(self.error("number or `[`"));
+            }
+            let n = self
+                .line
+                .parse(&self.line.text[start..self.pos], "number")?;
+            Ok(Elem::Number(n))
+        }
+    }
+
+    fn next_pair(&mut self) -> Result<Pair, ParseError> {
+        if self.depth == self.max_depth {
+            return Err(self.error(&format!(
+                "number, pairs nest at most {} deep",
+                self.max_depth
+            )));
+        }
+        self.expect('[')?;
+        self.depth += 1;
+        let l = self.next_elem()?;
+        self.expect(',')?;
+        let r = self.next_elem()?;
+        self.expect(']')?;
+        self.depth -= 1;
+        Ok(Pair { elems: [l, r] })
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Pair>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Pair>, Error> {
+        let pairs = parse::lines(input)
+            .map(|line| Pair::parse(&line))
+            .collect::<Result<Vec<_>, _>>()?;
+        if pairs.is_empty() {
+            return Err(ParseError::new(1, 1, "snailfish number").into());
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Vec<Pair>) -> Result<u64, Error> {
+        let mut pairs = pairs.iter();
+        let mut sum = pairs.next().unwrap().clone();
+        for pair in pairs {
+            sum = Pair::add(&sum, pair)?;
+        }
+        Ok(sum.magnitude()?)
+    }
+
+    fn part2(pairs: &Vec<Pair>) -> Result<u64, Error> {
+        let mut largest_magnitude = u64::MIN;
+        for (i, a) in pairs.iter().enumerate() {
+            for (j, b) in pairs.iter().enumerate() {
+                // Sum of two different numbers.
+                if i == j {
+                    continue;
+                }
+                let magnitude = Pair::add(a, b)?.magnitude()?;
+                largest_magnitude = cmp::max(largest_magnitude, magnitude);
+            }
+        }
+        Ok(largest_magnitude)
+    }
+}
+
+/// Sum of the numbers in the list so far, reduced one action at a time.
+#[derive(Clone)]
+pub struct Sum {
+    sum: Pair,
+    /// Index of the next number to add.
+    next: usize,
+    /// What the last step did.
+    action: String,
+}
+
+impl fmt::Display for Sum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.action, self.sum)
+    }
+}
+
+impl Simulate for Day18 {
+    type State = Sum;
+
+    fn start(pairs: &Vec<Pair>) -> Sum {
+        Sum {
+            sum: pairs[0].clone(),
+            next: 1,
+            action: "number 1".to_owned(),
+        }
+    }
+
+    /// Over when every number is added and the sum is reduced.
+    fn step(pairs: &Vec<Pair>, sum: &Sum) -> Result<Option<Sum>, Error> {
+        let mut sum = sum.clone();
+        if let Some(action) = sum.sum.reduce_action()? {
+            sum.action = action.to_owned();
+        } else if let Some(pair) = pairs.get(sum.next) {
+            sum.sum = Pair::concat(&sum.sum, pair);
+            sum.next += 1;
+            sum.action = format!("add number {}", sum.next);
+        } else {
+            return Ok(None);
+        }
+        Ok(Some(sum))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Also unreduced, with pairs nested one level deeper than in input.
+    fn pair(s: &str) -> Pair {
+        Pair::parse_with_depth(&parse::lines(s).next().unwrap(), MAX_DEPTH + 1).unwrap()
+    }
+
+    fn elem(s: &str) -> Elem {
+        let line = parse::lines(s).next().unwrap();
+        let mut parser = Parser {
+            line: &line,
+            pos: 0,
+            depth: 0,
+            max_depth: MAX_DEPTH + 1,
+        };
+        let elem = parser.next_elem().unwrap();
+        assert!(parser.eof(), "while parsing: `{}`", s);
+        elem
+    }
+
+    fn test_explode_impl(input: &str, expected: &str) {
+        let mut actual = pair(input);
+        let expected = pair(expected);
+
+        actual.explode().unwrap();
+        assert_eq!(
+            expected, actual,
+            "\ninput:    {}\nexpected: {}\nactual:   {}",
+            input, expected, actual
+        );
+    }
+
+    #[test]
+    fn test_explode() {
+        test_explode_impl("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]");
+        test_explode_impl("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]");
+        test_explode_impl("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]");
+        test_explode_impl(
+            "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+            "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+        );
+        test_explode_impl(
+            "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+        );
+    }
+
+    fn test_split_impl(input: &str, expected: &str) {
+        let mut actual = elem(input);
+        actual.split();
+        assert_eq!(
+            expected,
+            actual.to_string(),
+            "\ninput:    {}\nexpected: {}\nactual:   {}",
+            input,
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        test_split_impl("10", "[5,5]");
+        test_split_impl("11", "[5,6]");
+        test_split_impl("12", "[6,6]");
+    }
+
+    fn test_sum_impl(a: &str, b: &str, c: &str) {
+        let actual = Pair::add(&pair(a), &pair(b)).unwrap();
+        assert_eq!(
+            c,
+            actual.to_string(),
+            "\ninput:    {} + {}\nexpected: {}\nactual:   {}",
+            a,
+            b,
+            c,
+            actual
+        );
+    }
+
+    fn test_sum_list_impl(args: &[&str], expected: &str) {
+        let actual = args
+            .iter()
+            .map(|a| pair(a))
+            .reduce(|a, b| Pair::add(&a, &b).unwrap())
+            .unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn test_sum() {
+        test_sum_impl(
+            "[[[[3,3],4],4],[7,[[8,4],9]]]",
+            "[1,1]",
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+        );
+        test_sum_list_impl(
+            &["[1,1]", "[2,2]", "[3,3]", "[4,4]"],
+            "[[[[1,1],[2,2]],[3,3]],[4,4]]",
+        );
+        test_sum_list_impl(
+            &["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]"],
+            "[[[[3,0],[5,3]],[4,4]],[5,5]]",
+        );
+        test_sum_list_impl(
+            &["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"],
+            "[[[[5,0],[7,4]],[5,5]],[6,6]]",
+        );
+        test_sum_list_impl(
+            &[
+                "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+                "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+                "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+                "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+                "[7,[5,[[3,8],[1,4]]]]",
+                "[[2,[2,2]],[8,[8,1]]]",
+                "[2,9]",
+                "[1,[[[9,3],9],[[9,0],[0,7]]]]",
+                "[[[5,[7,4]],7],1]",
+                "[[[[4,2],2],6],[8,7]]",
+            ],
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+        );
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(Ok(143), pair("[[1,2],[[3,4],5]]").magnitude());
+        assert_eq!(
+            Ok(1384),
+            pair("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").magnitude()
+        );
+        assert_eq!(Ok(445), pair("[[[[1,1],[2,2]],[3,3]],[4,4]]").magnitude());
+        assert_eq!(Ok(791), pair("[[[[3,0],[5,3]],[4,4]],[5,5]]").magnitude());
+        assert_eq!(Ok(1137), pair("[[[[5,0],[7,4]],[5,5]],[6,6]]").magnitude());
+        assert_eq!(
+            3488,
+            pair("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")
+                .magnitude()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        let line = parse::lines("[1,x]").next().unwrap();
+        assert_eq!(
+            ParseError::new(1, 4, "number or `[`"),
+            Pair::parse(&line).unwrap_err()
+        );
+        let line = parse::lines("[1,2]]").next().unwrap();
+        assert_eq!(
+            ParseError::new(1, 6, "end of line"),
+            Pair::parse(&line).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn too_deep() {
+        let line = parse::lines("[[[[[1,2],3],4],5],6]").next().unwrap();
+        assert_eq!(
+            ParseError::new(1, 5, "number, pairs nest at most 4 deep"),
+            Pair::parse(&line).unwrap_err()
+        );
+    }
+}
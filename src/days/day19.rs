@@ -1,51 +1,40 @@
-use std::cmp::max;
 use std::collections::HashSet;
 use std::fmt;
-use std::fs;
 use std::ops::Add;
 use std::ops::Mul;
 use std::ops::Neg;
 use std::ops::Sub;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use crate::debug;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Error;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 enum Axis {
+    #[default]
     X,
     Y,
     Z,
 }
 
-impl Default for Axis {
-    fn default() -> Self {
-        Axis::X
-    }
-}
-
 impl Axis {
+    #[cfg(test)]
     const ALL: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
-
-    const fn id(&self) -> Vector {
-        match self {
-            Axis::X => Vector::new(1, 0, 0),
-            Axis::Y => Vector::new(0, 1, 0),
-            Axis::Z => Vector::new(0, 0, 1),
-        }
-    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 enum Angle90 {
+    #[default]
     A0,
     A90,
     A180,
     A270,
 }
 
-impl Default for Angle90 {
-    fn default() -> Self {
-        Angle90::A0
-    }
-}
-
 impl Add for Angle90 {
     type Output = Angle90;
 
@@ -75,8 +64,6 @@ impl Neg for Angle90 {
 }
 
 impl Angle90 {
-    const ALL: [Angle90; 4] = [Angle90::A0, Angle90::A90, Angle90::A180, Angle90::A270];
-
     fn sin(&self) -> i64 {
         match self {
             Angle90::A0 => 0,
@@ -161,48 +148,18 @@ impl Vector {
         Vector { data: [x, y, z] }
     }
 
-    fn rot_z(&self, angle: Angle90) -> Vector {
-        Matrix::rot_z(angle).mul(*self)
-    }
-
-    fn rot_x(&self, angle: Angle90) -> Vector {
-        Matrix::rot_x(angle).mul(*self)
-    }
-
-    fn rot_y(&self, angle: Angle90) -> Vector {
-        Matrix::rot_y(angle).mul(*self)
-    }
-
-    fn rot(&self, axis: Axis, angle: Angle90) -> Vector {
-        match axis {
-            Axis::X => self.rot_x(angle),
-            Axis::Y => self.rot_y(angle),
-            Axis::Z => self.rot_z(angle),
-        }
-    }
-
-    fn dot_product(&self, rhs: Vector) -> i64 {
-        self.data.iter().zip(rhs.data).map(|(a, b)| a * b).sum()
-    }
-
-    fn parse(s: &str) -> Vector {
-        let mut parts = s.split(",");
-        let x = parts.next().unwrap().parse::<i64>().unwrap();
-        let y = parts.next().unwrap().parse::<i64>().unwrap();
-        let z = parts.next().unwrap().parse::<i64>().unwrap();
-        Vector::new(x, y, z)
+    fn parse(line: &Line) -> Result<Vector, ParseError> {
+        let (x, yz) = line.split_once(line.text, ",")?;
+        let (y, z) = line.split_once(yz, ",")?;
+        let x = line.parse(x, "x coordinate")?;
+        let y = line.parse(y, "y coordinate")?;
+        let z = line.parse(z, "z coordinate")?;
+        Ok(Vector::new(x, y, z))
     }
 
     fn manhattan_distance(&self) -> i64 {
         self.data.iter().map(|x| x.abs()).sum()
     }
-
-    fn test() {
-        assert_eq!(
-            Vector::new(-2, 1, 3),
-            Vector::new(1, 2, 3).rot_z(Angle90::A90)
-        );
-    }
 }
 
 impl Mul<i64> for Matrix {
@@ -258,8 +215,7 @@ impl Mul<Matrix> for Matrix {
         let mut result = Matrix::default();
         for i in 0..3 {
             for j in 0..3 {
-                result.data[i][j] = 0
-                    + self.data[i][0] * rhs.data[0][j]
+                result.data[i][j] = self.data[i][0] * rhs.data[0][j]
                     + self.data[i][1] * rhs.data[1][j]
                     + self.data[i][2] * rhs.data[2][j];
             }
@@ -320,6 +276,7 @@ impl Matrix {
         }
     }
 
+    #[cfg(test)]
     fn rot_to_x(axis: Axis) -> Matrix {
         match axis {
             Axis::X => Matrix::ID,
@@ -331,16 +288,6 @@ impl Matrix {
     fn all_24() -> [Matrix; 24] {
         Rot::ALL_24.map(|rot| rot.matrix())
     }
-
-    fn test() {
-        assert_eq!(24, HashSet::<Matrix>::from_iter(Self::all_24()).len());
-
-        for axis in Axis::ALL {
-            let rot_x = Matrix::rot_x_to(axis);
-            let rot_to_x = Matrix::rot_to_x(axis);
-            assert_eq!(Matrix::ID, rot_x * rot_to_x);
-        }
-    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -349,12 +296,6 @@ struct VectorList {
 }
 
 impl VectorList {
-    fn print(&self) {
-        for vector in &self.data {
-            println!("{}", vector);
-        }
-    }
-
     fn shift(&self, offset: Vector) -> VectorList {
         VectorList {
             data: self.data.iter().map(|vector| *vector + offset).collect(),
@@ -404,6 +345,7 @@ impl Rot {
         Matrix::rot_x(self.x_axis_rot) * forward * Matrix::rot_x_to(self.axis)
     }
 
+    #[cfg(test)]
     fn matrix_rev(&self) -> Matrix {
         let forward = match self.forward {
             true => Matrix::ID,
@@ -535,15 +477,6 @@ impl Rot {
             forward: false,
         },
     ];
-
-    fn test() {
-        assert_eq!(Matrix::ID, Rot::ALL_24[0].matrix());
-
-        for rot in Rot::ALL_24 {
-            let id = rot.matrix() * rot.matrix_rev();
-            assert_eq!(Matrix::ID, id);
-        }
-    }
 }
 
 #[derive(Clone, PartialEq)]
@@ -588,10 +521,6 @@ struct ScannerOffset {
 }
 
 impl ScannerOffset {
-    fn orig(&self) -> Scanner {
-        self.scanner.offset(self.offset)
-    }
-
     fn new(scanner: &Scanner, offset: Vector) -> ScannerOffset {
         let scanner = scanner.offset(-offset);
         ScannerOffset { scanner, offset }
@@ -624,11 +553,9 @@ impl ScannerOffsets {
                 .offsets
                 .iter()
                 .filter_map(|o| {
-                    if let Some(intersect) = self_offset.intersect(o) {
-                        Some((self_offset.offset - o.offset, intersect))
-                    } else {
-                        None
-                    }
+                    self_offset
+                        .intersect(o)
+                        .map(|intersect| (self_offset.offset - o.offset, intersect))
                 })
                 .collect();
             assert!(offsets.len() <= 1);
@@ -665,7 +592,7 @@ impl ScannerRotsOffsets {
     }
 }
 
-struct Input {
+pub struct Input {
     scanners: Vec<Scanner>,
     scanner_rots_offsets: Vec<ScannerRotsOffsets>,
 }
@@ -675,37 +602,43 @@ impl Input {
         self.scanners.iter().map(|s| s.beacons.len()).max().unwrap()
     }
 
-    fn parse_scanners(filename: &str, check_index: bool) -> Vec<Scanner> {
-        let content = fs::read_to_string(filename).expect("Something went wrong reading the file");
+    fn parse_scanners(content: &str, check_index: bool) -> Result<Vec<Scanner>, ParseError> {
         let mut scanners: Vec<Scanner> = Vec::new();
-        let mut lines = content.lines();
+        let mut lines = parse::lines(content);
 
         let mut i = 0;
         'full: loop {
-            let line = lines.next().unwrap();
-            assert!(line.starts_with("--- scanner "));
-            let line = &line["--- scanner ".len()..];
-            assert!(line.ends_with(" ---"));
-            let line = &line[..line.len() - " ---".len()];
-            let scanner_id: u32 = line.to_string().parse().unwrap();
-            assert_eq!(if check_index { i } else { 0 }, scanner_id);
+            let line = lines.next_line("scanner header")?;
+            let id = line.strip_prefix(line.text, "--- scanner ")?;
+            let id = match id.strip_suffix(" ---") {
+                Some(id) => id,
+                None => return Err(line.error_at_end("` ---`")),
+            };
+            let scanner_id: u32 = line.parse(id, "scanner id")?;
+            if scanner_id != if check_index { i } else { 0 } {
+                return Err(line.error(id, format!("scanner {}", i)));
+            }
 
             let mut beacons: Vec<Vector> = Vec::new();
 
             loop {
                 match lines.next() {
                     None => {
-                        assert!(beacons.len() > 0);
+                        if beacons.is_empty() {
+                            return Err(lines.error_after_last("beacon"));
+                        }
                         scanners.push(Scanner::new(beacons));
                         break 'full;
                     }
-                    Some(line) if line.is_empty() => {
-                        assert!(beacons.len() > 0);
+                    Some(line) if line.text.is_empty() => {
+                        if beacons.is_empty() {
+                            return Err(line.error(line.text, "beacon"));
+                        }
                         scanners.push(Scanner::new(beacons));
                         break;
                     }
                     Some(line) => {
-                        let beacon = Vector::parse(line);
+                        let beacon = Vector::parse(&line)?;
                         beacons.push(beacon);
                     }
                 }
@@ -714,19 +647,18 @@ impl Input {
             i += 1;
         }
 
-        assert!(scanners.len() > 0);
-        scanners
+        Ok(scanners)
     }
 
-    fn parse(filename: &str) -> Input {
-        let scanners = Input::parse_scanners(filename, true);
+    fn parse(content: &str) -> Result<Input, ParseError> {
+        let scanners = Input::parse_scanners(content, true)?;
 
         let scanner_rots = scanners.iter().map(ScannerRotsOffsets::new).collect();
 
-        Input {
+        Ok(Input {
             scanners,
             scanner_rots_offsets: scanner_rots,
-        }
+        })
     }
 }
 
@@ -756,77 +688,8 @@ impl Mul<Transform> for Transform {
     }
 }
 
-impl Transform {
-    fn test_mul_vec() {
-        assert_eq!(
-            Vector::new(12, 23, 31),
-            Transform {
-                offset: Vector::new(10, 20, 30),
-                rot: Rot {
-                    x_axis_rot: Angle90::A90,
-                    axis: Axis::Y,
-                    forward: false,
-                },
-            } * Vector::new(1, 2, 3)
-        );
-    }
-
-    fn test_mul_tr() {
-        let tr1 = Transform {
-            offset: Vector::new(10, 20, 30),
-            rot: Rot::from_matrix(&Matrix::rot_z(Angle90::A90)),
-        };
-        let tr2 = Transform {
-            offset: Vector::new(1, 2, 3),
-            rot: Rot::from_matrix(&Matrix::rot_y(Angle90::A270)),
-        };
-        let tr3 = tr1 * tr2;
-        // let expected = Transform {
-        //     offset: Vector::new(10, 20, 30),
-        //     rot: Rot {
-        //
-        //     },
-        // };
-        // assert_eq!(expected, tr3);
-    }
-
-    fn test() {
-        Self::test_mul_vec();
-        Self::test_mul_tr();
-    }
-}
-
-fn test_test_input(input: &Input) {
-    let offset_0_1 = input.scanner_rots_offsets[0].scanners_by_rot[0]
-        .intersect_any(&input.scanner_rots_offsets[1])
-        .unwrap();
-    // println!("{:?}", offset_0_1);
-    // offset_0_1.2.print();
-    // println!("XX");
-    // println!();
-    // println!("actual:");
-    // println!("{}", offset_0_1.0);
-    // println!();
-    // println!("expected:");
-    // println!("{}", Vector::new(68, -1246, -43));
-    // println!();
-    assert_eq!(Vector::new(68, -1246, -43), offset_0_1.0.offset);
-
-    let offset_1_4 = input.scanner_rots_offsets[1].scanners_by_rot[0]
-        .intersect_any(&input.scanner_rots_offsets[4])
-        .unwrap();
-}
-
-fn part1(filename: &str, test: bool) {
-    println!("{}", filename);
-    let input = Input::parse(filename);
-
-    if test {
-        test_test_input(&input);
-    }
-    // return;
-
-    println!("max beacons: {}", input.max_beacons());
+fn resolve_scanners(input: &Input) -> Result<Vec<Transform>, Error> {
+    debug!("max beacons: {}", input.max_beacons());
 
     let mut resolved_scanners: Vec<Option<Transform>> = vec![None; input.scanners.len()];
     resolved_scanners[0] = Some(Transform {
@@ -836,92 +699,212 @@ fn part1(filename: &str, test: bool) {
 
     let mut pairs_checked = HashSet::new();
 
-    while resolved_scanners.iter().any(|r| r.is_none()) {
+    while let Some(unresolved) = resolved_scanners.iter().position(|r| r.is_none()) {
+        let mut progress = false;
         for i in 0..input.scanners.len() {
-            println!(
+            trace!(
                 "rem {}; i: {}",
                 resolved_scanners.iter().filter(|r| r.is_none()).count(),
                 i
             );
-            let i_resolved = match resolved_scanners[i].clone() {
+            let i_resolved = match resolved_scanners[i] {
                 Some(r) => r,
                 None => {
-                    // println!("resolved");
+                    trace!("{} not resolved yet", i);
                     continue;
                 }
             };
+            #[allow(clippy::needless_range_loop)]
             for j in 0..input.scanners.len() {
                 if resolved_scanners[j].is_some() {
-                    // println!("skipping some {} {}", i, j);
+                    trace!("skipping resolved {} {}", i, j);
                     continue;
                 }
 
                 if !pairs_checked.insert((i, j)) {
-                    // println!("skipping checked {} {}", i, j);
+                    trace!("skipping checked {} {}", i, j);
                     continue;
                 }
 
-                // println!("i: {}, j: {}", i, j);
                 if let Some((tr, _beacons)) = input.scanner_rots_offsets[i].scanners_by_rot[0]
                     .intersect_any(&input.scanner_rots_offsets[j])
                 {
-                    // println!("hit");
+                    debug!("resolved {} from {}", j, i);
                     resolved_scanners[j] = Some(i_resolved * tr);
+                    progress = true;
                 }
-                // println!("i: {}, j: {}.", i, j);
             }
-            // println!("i: {}.", i);
+        }
+        // Every pair of resolved and unresolved scanners is checked.
+        if !progress {
+            return Err(Error::NoAnswer(format!(
+                "scanner {} cannot be resolved",
+                unresolved
+            )));
         }
     }
 
-    let mut all_beacons: HashSet<Vector> = HashSet::new();
+    Ok(resolved_scanners.into_iter().map(|r| r.unwrap()).collect())
+}
 
-    for i in 0..input.scanners.len() {
-        let resolved = resolved_scanners[i].unwrap();
-        let beacons = &input.scanners[i];
-        for &beacon in &beacons.beacons {
-            let beacon = resolved * beacon;
-            all_beacons.insert(beacon);
-        }
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Input, Error> {
+        Ok(Input::parse(input)?)
     }
 
-    println!("{}", all_beacons.len());
-    assert!(all_beacons.len() == 79 || all_beacons.len() == 390);
+    fn part1(input: &Input) -> Result<usize, Error> {
+        let resolved_scanners = resolve_scanners(input)?;
 
-    let mut max_dist = 0;
-    for a in &resolved_scanners {
-        for b in &resolved_scanners {
-            let dist = (a.unwrap().offset - b.unwrap().offset).manhattan_distance();
-            if dist > max_dist {
-                max_dist = dist;
+        let mut all_beacons: HashSet<Vector> = HashSet::new();
+
+        for (resolved, scanner) in resolved_scanners.iter().zip(&input.scanners) {
+            for &beacon in &scanner.beacons {
+                let beacon = *resolved * beacon;
+                all_beacons.insert(beacon);
             }
         }
+
+        Ok(all_beacons.len())
     }
 
-    println!("max_dist: {}", max_dist);
-    assert!(max_dist == 3621 || max_dist == 13327);
-}
+    fn part2(input: &Input) -> Result<i64, Error> {
+        let resolved_scanners = resolve_scanners(input)?;
 
-fn test_sample_rots() {
-    let mut scanners = Input::parse_scanners("day19-sample-rots.txt", false);
-    let first = scanners.swap_remove(0);
-    for rot in Rot::ALL_24 {
-        let rotated = first.rot(rot.matrix());
-        if let Some(i) = scanners.iter().position(|s| s == &rotated) {
-            scanners.swap_remove(i);
+        let mut max_dist = 0;
+        for a in &resolved_scanners {
+            for b in &resolved_scanners {
+                let dist = (a.offset - b.offset).manhattan_distance();
+                if dist > max_dist {
+                    max_dist = dist;
+                }
+            }
         }
+
+        Ok(max_dist)
     }
-    assert!(scanners.is_empty());
 }
 
-fn main() {
-    Vector::test();
-    Matrix::test();
-    Rot::test();
-    Transform::test();
-    test_sample_rots();
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn vector() {
+        assert_eq!(
+            Vector::new(-2, 1, 3),
+            Matrix::rot_z(Angle90::A90) * Vector::new(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn matrix() {
+        assert_eq!(24, HashSet::<Matrix>::from_iter(Matrix::all_24()).len());
+
+        for axis in Axis::ALL {
+            let rot_x = Matrix::rot_x_to(axis);
+            let rot_to_x = Matrix::rot_to_x(axis);
+            assert_eq!(Matrix::ID, rot_x * rot_to_x);
+        }
+    }
+
+    #[test]
+    fn rot() {
+        assert_eq!(Matrix::ID, Rot::ALL_24[0].matrix());
+
+        for rot in Rot::ALL_24 {
+            let id = rot.matrix() * rot.matrix_rev();
+            assert_eq!(Matrix::ID, id);
+        }
+    }
+
+    #[test]
+    fn transform_mul_vec() {
+        assert_eq!(
+            Vector::new(12, 23, 31),
+            Transform {
+                offset: Vector::new(10, 20, 30),
+                rot: Rot {
+                    x_axis_rot: Angle90::A90,
+                    axis: Axis::Y,
+                    forward: false,
+                },
+            } * Vector::new(1, 2, 3)
+        );
+    }
 
-    println!("Part 1");
-    part1("day19-input-test.txt", true);
-    part1("day19-input.txt", false);
+    #[test]
+    fn transform_mul_tr() {
+        let tr1 = Transform {
+            offset: Vector::new(10, 20, 30),
+            rot: Rot::from_matrix(&Matrix::rot_z(Angle90::A90)),
+        };
+        let tr2 = Transform {
+            offset: Vector::new(1, 2, 3),
+            rot: Rot::from_matrix(&Matrix::rot_y(Angle90::A270)),
+        };
+        let _tr3 = tr1 * tr2;
+        // let expected = Transform {
+        //     offset: Vector::new(10, 20, 30),
+        //     rot: Rot {
+        //
+        //     },
+        // };
+        // assert_eq!(expected, tr3);
+    }
+
+    #[test]
+    fn test_input() {
+        let content = fs::read_to_string("inputs/day19/example.txt").unwrap();
+        let input = Input::parse(&content).unwrap();
+
+        let offset_0_1 = input.scanner_rots_offsets[0].scanners_by_rot[0]
+            .intersect_any(&input.scanner_rots_offsets[1])
+            .unwrap();
+        // println!("{:?}", offset_0_1);
+        // offset_0_1.2.print();
+        // println!("XX");
+        // println!();
+        // println!("actual:");
+        // println!("{}", offset_0_1.0);
+        // println!();
+        // println!("expected:");
+        // println!("{}", Vector::new(68, -1246, -43));
+        // println!();
+        assert_eq!(Vector::new(68, -1246, -43), offset_0_1.0.offset);
+
+        let _offset_1_4 = input.scanner_rots_offsets[1].scanners_by_rot[0]
+            .intersect_any(&input.scanner_rots_offsets[4])
+            .unwrap();
+    }
+
+    #[test]
+    fn sample_rots() {
+        let content = fs::read_to_string("inputs/day19/rotations.txt").unwrap();
+        let mut scanners = Input::parse_scanners(&content, false).unwrap();
+        let first = scanners.swap_remove(0);
+        for rot in Rot::ALL_24 {
+            let rotated = first.rot(rot.matrix());
+            if let Some(i) = scanners.iter().position(|s| s == &rotated) {
+                scanners.swap_remove(i);
+            }
+        }
+        assert!(scanners.is_empty());
+    }
+
+    #[test]
+    fn unresolvable() {
+        let input = Input::parse("--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5,6\n").unwrap();
+        assert_eq!(
+            Err(Error::NoAnswer("scanner 1 cannot be resolved".to_owned())),
+            Day19::part1(&input)
+        );
+    }
 }
@@ -0,0 +1,194 @@
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::image::Frames;
+use crate::image::Image;
+use crate::image::Rgb;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Animate;
+use crate::Error;
+use crate::Simulate;
+use crate::Solution;
+
+fn parse_pixel(c: char) -> Option<bool> {
+    match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    }
+}
+
+struct Alg {
+    bits: [bool; 512],
+}
+
+impl Alg {
+    fn parse(line: &Line) -> Result<Alg, ParseError> {
+        let mut bits = [false; 512];
+        let mut chars = line.text.char_indices();
+        for bit in bits.iter_mut() {
+            *bit = match chars.next() {
+                Some((i, c)) => match parse_pixel(c) {
+                    Some(pixel) => pixel,
+                    None => return Err(line.error(&line.text[i..], "`.` or `#`")),
+                },
+                None => return Err(line.error_at_end("512 pixels")),
+            };
+        }
+        if let Some((i, _)) = chars.next() {
+            return Err(line.error(&line.text[i..], "end of line"));
+        }
+        Ok(Alg { bits })
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    default: bool,
+    grid: Grid<bool>,
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.grid.map(|&c| if c { '#' } else { '.' }))
+    }
+}
+
+impl Map {
+    fn at(&self, row: isize, col: isize) -> bool {
+        if row < 0 || col < 0 {
+            return self.default;
+        }
+        match self.grid.get((row as usize, col as usize)) {
+            Some(c) => *c,
+            None => self.default,
+        }
+    }
+
+    fn nine_at(&self, r: isize, c: isize) -> u32 {
+        let mut sum = 0;
+        for ro in -1..=1 {
+            for co in -1..=1 {
+                sum = (sum << 1) + (self.at(r + ro, c + co) as u32);
+            }
+        }
+        sum
+    }
+
+    fn apply(&self, alg: &Alg) -> Map {
+        let mut grid = Grid::new(self.grid.rows() + 2, self.grid.cols() + 2, false);
+        for r in 0..grid.rows() {
+            for c in 0..grid.cols() {
+                let or = r as isize - 1;
+                let oc = c as isize - 1;
+                let nine = self.nine_at(or, oc);
+                grid[(r, c)] = alg.bits[nine as usize];
+            }
+        }
+        let default = alg.bits[if self.default { 511 } else { 0 }];
+        Map { grid, default }
+    }
+
+    fn image(&self) -> Image {
+        Image::from_grid(&self.grid, |&lit| if lit { Rgb::WHITE } else { Rgb::BLACK })
+    }
+
+    fn lit_pixels(&self) -> usize {
+        assert!(!self.default);
+        self.grid.values().filter(|&&c| c).count()
+    }
+}
+
+pub struct Input {
+    alg: Alg,
+    map: Map,
+}
+
+impl Input {
+    fn parse(contents: &str) -> Result<Input, ParseError> {
+        let mut lines = parse::lines(contents);
+        let alg = Alg::parse(&lines.next_line("enhancement algorithm")?)?;
+        lines.next_line("empty line")?.expect("")?;
+
+        let grid = Grid::from_char_lines(&mut lines, "`.` or `#`", parse_pixel)?;
+        let default = false;
+        let map = Map { grid, default };
+        Ok(Input { alg, map })
+    }
+}
+
+fn run(input: &Input, steps: usize) -> usize {
+    let mut map = input.map.clone();
+    trace!("init map:\n{}", map);
+
+    for i in 0..steps {
+        map = map.apply(&input.alg);
+        trace!("after step {}:\n{}", i, map);
+    }
+
+    map.lit_pixels()
+}
+
+pub struct Day20;
+
+impl Animate for Day20 {
+    /// All 50 enhancement steps of part 2. The infinite background beyond
+    /// the grid is not drawn.
+    fn animate(input: &Input, frames: &mut Frames) -> Result<(), Error> {
+        let mut map = input.map.clone();
+        frames.push(&map.image())?;
+        for _ in 0..50 {
+            map = map.apply(&input.alg);
+            frames.push(&map.image())?;
+        }
+        Ok(())
+    }
+}
+
+impl Simulate for Day20 {
+    type State = Map;
+
+    fn start(input: &Input) -> Map {
+        input.map.clone()
+    }
+
+    fn step(input: &Input, map: &Map) -> Result<Option<Map>, Error> {
+        Ok(Some(map.apply(&input.alg)))
+    }
+}
+
+impl Solution for Day20 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Input, Error> {
+        Ok(Input::parse(input)?)
+    }
+
+    fn part1(input: &Input) -> Result<usize, Error> {
+        Ok(run(input, 2))
+    }
+
+    fn part2(input: &Input) -> Result<usize, Error> {
+        Ok(run(input, 50))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let content = fs::read_to_string("inputs/day20/example.txt").unwrap();
+        let input = Input::parse(&content).unwrap();
+        assert_eq!(34, input.map.nine_at(2, 2));
+        assert!(input.alg.bits[34]);
+    }
+}
@@ -0,0 +1,204 @@
+use std::cmp;
+use std::collections::HashMap;
+
+use crate::checked;
+use crate::checked::Overflow;
+use crate::parse;
+use crate::parse::ParseError;
+use crate::Error;
+use crate::Solution;
+
+struct Dice {
+    how_many_times: u32,
+    next: u32,
+}
+
+impl Dice {
+    fn next(&mut self) -> u32 {
+        self.how_many_times += 1;
+        let r = self.next;
+        self.next = if self.next == 100 { 1 } else { self.next + 1 };
+        r
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Hash, Clone, Copy)]
+struct Player {
+    pos: u32,
+    score: u32,
+}
+
+impl Player {
+    fn new(pos: u32) -> Player {
+        Player { pos, score: 0 }
+    }
+
+    fn turn(&mut self, dice: &mut Dice) -> Result<(), Overflow> {
+        let sum = dice.next() + dice.next() + dice.next();
+        self.pos = ((self.pos - 1) + sum) % 10 + 1;
+        self.score = checked::add(self.score, self.pos)?;
+        Ok(())
+    }
+}
+
+fn parse(input: &str) -> Result<(u32, u32), ParseError> {
+    let mut lines = parse::lines(input);
+    let mut next = |player: u32| -> Result<u32, ParseError> {
+        let line = lines.next_line(&format!("player {} starting position", player))?;
+        let prefix = format!("Player {} starting position: ", player);
+        let pos = line.strip_prefix(line.text, &prefix)?;
+        match line.parse(pos, "position 1..=10")? {
+            pos @ 1..=10 => Ok(pos),
+            _ => Err(line.error(pos, "position 1..=10")),
+        }
+    };
+    let p1_start = next(1)?;
+    let p2_start = next(2)?;
+    Ok((p1_start, p2_start))
+}
+
+#[derive(Eq, PartialEq, Debug, Hash, Clone, Copy)]
+struct GameState {
+    p1: Player,
+    p2: Player,
+}
+
+impl GameState {
+    fn flip(&self) -> GameState {
+        GameState {
+            p1: self.p2,
+            p2: self.p1,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct Wins {
+    p1: u64,
+    p2: u64,
+}
+
+impl Wins {
+    fn add(self, rhs: Wins) -> Result<Wins, Overflow> {
+        Ok(Wins {
+            p1: checked::add(self.p1, rhs.p1)?,
+            p2: checked::add(self.p2, rhs.p2)?,
+        })
+    }
+
+    fn flip(&self) -> Wins {
+        Wins {
+            p1: self.p2,
+            p2: self.p1,
+        }
+    }
+}
+
+struct Quantum {
+    memo: HashMap<GameState, Wins>,
+}
+
+impl Quantum {
+    // 111 -> 3
+    // 112 -> 4
+    // 113 -> 5
+    // 121 -> 4
+    // 122 -> 5
+    // 123 -> 6
+    // 131 -> 5
+    // 132 -> 6
+    // 133 -> 7
+    // 211 -> 4
+    // 212 -> 5
+    // 213 -> 6
+    // 221 -> 5
+    // 222 -> 6
+    // 223 -> 7
+    // 231 -> 6
+    // 232 -> 7
+    // 233 -> 8
+    // 311 -> 5
+    // 312 -> 6
+    // 313 -> 7
+    // 321 -> 6
+    // 322 -> 7
+    // 323 -> 8
+    // 331 -> 7
+    // 332 -> 8
+    // 333 -> 9
+    const DICE_ROLLS: [u32; 27] = [
+        3, 4, 5, 4, 5, 6, 5, 6, 7, 4, 5, 6, 5, 6, 7, 6, 7, 8, 5, 6, 7, 6, 7, 8, 7, 8, 9,
+    ];
+
+    fn solve(&mut self, game: &GameState) -> Result<Wins, Overflow> {
+        if let Some(wins) = self.memo.get(game) {
+            return Ok(*wins);
+        }
+
+        let mut wins = Wins::default();
+
+        for roll in Self::DICE_ROLLS {
+            let mut game = *game;
+            assert!(game.p1.score < 21);
+            assert!(game.p2.score < 21);
+
+            game.p1.pos = (game.p1.pos - 1 + roll) % 10 + 1;
+            game.p1.score += game.p1.pos;
+            if game.p1.score >= 21 {
+                wins.p1 = checked::add(wins.p1, 1)?;
+            } else {
+                wins = wins.add(self.solve(&game.flip())?.flip())?;
+            }
+        }
+
+        self.memo.insert(*game, wins);
+        Ok(wins)
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (u32, u32);
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<(u32, u32), Error> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(start: &(u32, u32)) -> Result<u32, Error> {
+        let (p1_start, p2_start) = *start;
+
+        let mut dice = Dice {
+            how_many_times: 0,
+            next: 1,
+        };
+
+        let mut p1 = Player::new(p1_start);
+        let mut p2 = Player::new(p2_start);
+
+        loop {
+            p1.turn(&mut dice)?;
+            if p1.score >= 1000 {
+                return Ok(checked::mul(p2.score, dice.how_many_times)?);
+            }
+            p2.turn(&mut dice)?;
+            if p2.score >= 1000 {
+                return Ok(checked::mul(p1.score, dice.how_many_times)?);
+            }
+        }
+    }
+
+    fn part2(start: &(u32, u32)) -> Result<u64, Error> {
+        let (p1_start, p2_start) = *start;
+        let mut quantum = Quantum {
+            memo: HashMap::new(),
+        };
+        let wins = quantum.solve(&GameState {
+            p1: Player::new(p1_start),
+            p2: Player::new(p2_start),
+        })?;
+        Ok(cmp::max(wins.p1, wins.p2))
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::BTreeMap;
+
+use crate::checked;
+use crate::checked::Overflow;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Error;
+use crate::Solution;
+
+#[derive(Copy, Clone, Debug)]
+struct Range {
+    from: i64,
+    to_incl: i64,
+}
+
+impl Range {
+    fn parse(line: &Line, s: &str, axis: char) -> Result<Range, ParseError> {
+        let s = line.strip_prefix(s, &format!("{}=", axis))?;
+        let (from_s, to_s) = line.split_once(s, "..")?;
+        let from = line.parse::<i64>(from_s, "number")?;
+        let to_incl = line.parse::<i64>(to_s, "number")?;
+        if from > to_incl {
+            return Err(line.error(to_s, format!("number not less than {}", from)));
+        }
+        Ok(Range { from, to_incl })
+    }
+}
+
+#[derive(Debug)]
+struct Command {
+    on: bool,
+    coords: [Range; 3],
+}
+
+impl Command {
+    fn parse(line: &Line) -> Result<Command, ParseError> {
+        let s = line.text;
+        let (on, s) = if let Some(s) = s.strip_prefix("on ") {
+            (true, s)
+        } else if let Some(s) = s.strip_prefix("off ") {
+            (false, s)
+        } else {
+            return Err(line.error(s, "`on` or `off`"));
+        };
+        let (x, s) = line.split_once(s, ",")?;
+        let (y, z) = line.split_once(s, ",")?;
+        let x = Range::parse(line, x, 'x')?;
+        let y = Range::parse(line, y, 'y')?;
+        let z = Range::parse(line, z, 'z')?;
+        let coords = [x, y, z];
+        Ok(Command { on, coords })
+    }
+}
+
+pub struct Input {
+    commands: Vec<Command>,
+}
+
+impl Input {
+    fn parse(content: &str) -> Result<Input, ParseError> {
+        let commands = parse::lines(content)
+            .map(|line| Command::parse(&line))
+            .collect::<Result<_, _>>()?;
+        Ok(Input { commands })
+    }
+}
+
+#[derive(Default, Clone)]
+struct CuboidsChild {
+    eq: Cuboids,
+    gt: Cuboids,
+}
+
+#[derive(Default, Clone)]
+struct Cuboids {
+    on: bool,
+    children: BTreeMap<i64, CuboidsChild>,
+}
+
+impl Cuboids {
+    fn split(&mut self, at: i64) {
+        let range = self.children.range_mut(..=at);
+        match range.last() {
+            None => {
+                self.children.insert(at, CuboidsChild::default());
+            }
+            Some(last) => {
+                if *last.0 == at {
+                    return;
+                }
+                let child = CuboidsChild {
+                    eq: last.1.gt.clone(),
+                    gt: last.1.gt.clone(),
+                };
+                self.children.insert(at, child);
+            }
+        }
+    }
+
+    fn update(&mut self, key: &[Range], on: bool) {
+        match key.split_first() {
+            None => self.on = on,
+            Some((first, rem)) => {
+                self.split(first.from);
+                self.split(first.to_incl);
+
+                assert!(self.children.contains_key(&first.from));
+                assert!(self.children.contains_key(&first.to_incl));
+
+                let range = self.children.range_mut(first.from..=first.to_incl);
+                for (&k, child) in range {
+                    child.eq.update(rem, on);
+                    if k != first.to_incl {
+                        child.gt.update(rem, on);
+                    }
+                }
+            }
+        }
+    }
+
+    fn count_on(&self, ranges: &[Range]) -> Result<u64, Overflow> {
+        match ranges.split_first() {
+            None => Ok(self.on as u64),
+            Some((first, rem)) => {
+                assert!(self.children.contains_key(&first.from));
+                assert!(self.children.contains_key(&first.to_incl));
+                let range = self.children.range(first.from..=first.to_incl);
+
+                let mut sum = 0;
+
+                let mut range_again = range.clone();
+                range_again.next().unwrap();
+                for (&k_s, child) in range {
+                    let k_e = range_again.next();
+                    sum = checked::add(sum, child.eq.count_on(rem)?)?;
+                    match k_e {
+                        None => {
+                            assert!(k_s == first.to_incl);
+                        }
+                        Some(k_e) => {
+                            let k_e = *k_e.0;
+                            assert!(k_e > k_s);
+                            assert!(k_s < first.to_incl);
+                            let gap = (k_e - k_s - 1) as u64;
+                            sum = checked::add(sum, checked::mul(child.gt.count_on(rem)?, gap)?)?;
+                        }
+                    }
+                }
+                Ok(sum)
+            }
+        }
+    }
+}
+
+const FIFTY: [Range; 3] = [
+    Range {
+        from: -50,
+        to_incl: 50,
+    },
+    Range {
+        from: -50,
+        to_incl: 50,
+    },
+    Range {
+        from: -50,
+        to_incl: 50,
+    },
+];
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Input, Error> {
+        Ok(Input::parse(input)?)
+    }
+
+    fn part1(input: &Input) -> Result<u64, Error> {
+        let mut cuboids = Cuboids::default();
+        cuboids.update(&FIFTY, false);
+        for command in &input.commands {
+            trace!("{:?}", command);
+            cuboids.update(&command.coords, command.on);
+        }
+
+        let count_50 = cuboids.count_on(&FIFTY)?;
+        Ok(count_50)
+    }
+
+    fn part2(input: &Input) -> Result<u64, Error> {
+        let mut cuboids = Cuboids::default();
+
+        let millions = [
+            Range {
+                from: -1000000,
+                to_incl: 1000000,
+            },
+            Range {
+                from: -1000000,
+                to_incl: 1000000,
+            },
+            Range {
+                from: -1000000,
+                to_incl: 1000000,
+            },
+        ];
+
+        cuboids.update(&millions, false);
+
+        for command in &input.commands {
+            cuboids.update(&command.coords, command.on);
+        }
+
+        let count_1m = cuboids.count_on(&millions)?;
+        Ok(count_1m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn part1_test_1_steps() {
+        let content = fs::read_to_string("inputs/day22/example-part1-1.txt").unwrap();
+        let input = Input::parse(&content).unwrap();
+        let mut cuboids = Cuboids::default();
+        cuboids.update(&FIFTY, false);
+        for (i, command) in input.commands.iter().enumerate() {
+            cuboids.update(&command.coords, command.on);
+            if i == 0 {
+                assert_eq!(Ok(27), cuboids.count_on(&FIFTY));
+            } else if i == 1 {
+                assert_eq!(Ok(27 + 19), cuboids.count_on(&FIFTY));
+            }
+        }
+    }
+}
@@ -1,7 +1,14 @@
-#![feature(map_first_last)]
-
-use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::fmt;
+
+use crate::debug;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::search;
+use crate::trace;
+use crate::Error;
+use crate::Solution;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
 enum Amphipod {
@@ -14,6 +21,16 @@ enum Amphipod {
 impl Amphipod {
     const ALL: [Amphipod; 4] = [Amphipod::A, Amphipod::B, Amphipod::C, Amphipod::D];
 
+    fn parse(c: char) -> Option<Amphipod> {
+        match c {
+            'A' => Some(Amphipod::A),
+            'B' => Some(Amphipod::B),
+            'C' => Some(Amphipod::C),
+            'D' => Some(Amphipod::D),
+            _ => None,
+        }
+    }
+
     fn room_i(&self) -> usize {
         match self {
             Amphipod::A => 0,
@@ -34,32 +51,83 @@ impl Amphipod {
     }
 }
 
+const ROOM_COUNT: usize = 4;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct RoomInit {
-    amphipods: [Amphipod; Room::LEN],
+struct RoomInit<const LEN: usize> {
+    amphipods: [Amphipod; LEN],
 }
 
-struct Input {
-    rooms: [RoomInit; 4],
+pub struct Input<const LEN: usize> {
+    rooms: [RoomInit<LEN>; ROOM_COUNT],
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-struct Room {
-    amphipods: [Option<Amphipod>; Room::LEN],
+impl<const LEN: usize> Input<LEN> {
+    // Room rows are listed from the hallway down.
+    fn parse_rows(rows: &[Line]) -> Result<Input<LEN>, ParseError> {
+        assert_eq!(LEN, rows.len());
+        let mut rooms = [RoomInit {
+            amphipods: [Amphipod::A; LEN],
+        }; ROOM_COUNT];
+        for (i, row) in rows.iter().enumerate() {
+            for (room_i, room) in rooms.iter_mut().enumerate() {
+                let at = match row.text.get(3 + room_i * 2..) {
+                    Some(at) => at,
+                    None => return Err(row.error_at_end("amphipod")),
+                };
+                room.amphipods[LEN - 1 - i] = match at.chars().next().and_then(Amphipod::parse) {
+                    Some(amphipod) => amphipod,
+                    None => return Err(row.error(at, "amphipod")),
+                };
+            }
+        }
+        Ok(Input { rooms })
+    }
 }
 
-impl Room {
-    const LEN: usize = 4;
-    const COUNT: usize = 4;
+impl Input<2> {
+    fn parse(input: &str) -> Result<Input<2>, ParseError> {
+        let mut lines = parse::lines(input);
+        lines.next_line("wall")?.expect("#############")?;
+        lines.next_line("hallway")?.expect("#...........#")?;
+        let rows = [lines.next_line("room row")?, lines.next_line("room row")?];
+        let bottom = lines.next_line("wall")?;
+        if bottom.text.trim_end() != "  #########" {
+            return Err(bottom.error(bottom.text, "`  #########`"));
+        }
+        if let Some(line) = lines.next() {
+            return Err(line.error(line.text, "end of input"));
+        }
+        Input::parse_rows(&rows)
+    }
+
+    fn unfold(&self) -> Input<4> {
+        let mut rooms = [RoomInit {
+            amphipods: [Amphipod::A; 4],
+        }; ROOM_COUNT];
+        let inserted: Vec<Line> = parse::lines("  #D#C#B#A#\n  #D#B#A#C#").collect();
+        let inserted = Input::<2>::parse_rows(&inserted).unwrap();
+        for (room_i, room) in rooms.iter_mut().enumerate() {
+            room.amphipods = [
+                self.rooms[room_i].amphipods[0],
+                inserted.rooms[room_i].amphipods[0],
+                inserted.rooms[room_i].amphipods[1],
+                self.rooms[room_i].amphipods[1],
+            ];
+        }
+        Input { rooms }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Room<const LEN: usize> {
+    amphipods: [Option<Amphipod>; LEN],
+}
 
-    fn new(init: RoomInit) -> Self {
+impl<const LEN: usize> Room<LEN> {
+    fn new(init: RoomInit<LEN>) -> Self {
         Room {
-            amphipods: [
-                Some(init.amphipods[0]),
-                Some(init.amphipods[1]),
-                Some(init.amphipods[2]),
-                Some(init.amphipods[3]),
-            ],
+            amphipods: init.amphipods.map(Some),
         }
     }
 
@@ -69,17 +137,9 @@ impl Room {
             .all(|&amphipod_opt| amphipod_opt == Some(amphipod))
     }
 
-    fn is_full(&self) -> bool {
-        self.amphipods.iter().all(|amphipod| amphipod.is_some())
-    }
-
-    fn is_empty(&self) -> bool {
-        self.amphipods.iter().all(|amphipod| amphipod.is_none())
-    }
-
     fn can_insert(&self, amphipod: Amphipod) -> Option<usize> {
-        for i in 0..Room::LEN {
-            if let Some(next) = self.amphipods[i] {
+        for (i, place) in self.amphipods.iter().enumerate() {
+            if let Some(next) = *place {
                 if next != amphipod {
                     return None;
                 }
@@ -91,7 +151,7 @@ impl Room {
     }
 
     fn can_take(&self, room_id: Amphipod) -> Option<(usize, Amphipod)> {
-        for i in (0..Room::LEN).rev() {
+        for i in (0..LEN).rev() {
             match self.amphipods[i] {
                 Some(same) if same == room_id => {
                     return if self.amphipods[..i].iter().any(|next| next.unwrap() != same) {
@@ -154,43 +214,45 @@ impl Hallway {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
-struct State {
-    rooms: [Room; Room::COUNT],
+struct State<const LEN: usize> {
+    rooms: [Room<LEN>; ROOM_COUNT],
     hallway: Hallway,
 }
 
-impl State {
-    fn print(&self) {
+impl<const LEN: usize> fmt::Display for State<LEN> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for _ in 0..Hallway::LEN + 2 {
-            print!("#");
+            write!(f, "#")?;
         }
-        println!();
-        print!("#");
-        for hallway_i in 0..Hallway::LEN {
-            if let Some(an) = self.hallway.places[hallway_i] {
-                print!("{:?}", an);
+        writeln!(f)?;
+        write!(f, "#")?;
+        for place in &self.hallway.places {
+            if let Some(an) = place {
+                write!(f, "{:?}", an)?;
             } else {
-                print!(".");
+                write!(f, ".")?;
             }
         }
-        print!("#");
-        println!();
-        for i in (0..Room::LEN).rev() {
-            print!("{}", if i != Room::LEN - 1 { "  #" } else { "###" });
-            for room_i in 0..Room::COUNT {
-                if let Some(an) = self.rooms[room_i].amphipods[i] {
-                    print!("{:?}", an);
+        write!(f, "#")?;
+        writeln!(f)?;
+        for i in (0..LEN).rev() {
+            write!(f, "{}", if i != LEN - 1 { "  #" } else { "###" })?;
+            for room in &self.rooms {
+                if let Some(an) = room.amphipods[i] {
+                    write!(f, "{:?}", an)?;
                 } else {
-                    print!(".");
+                    write!(f, ".")?;
                 }
-                print!("#");
+                write!(f, "#")?;
             }
-            print!("{}", if i != Room::LEN - 1 { "" } else { "##" });
-            println!();
+            write!(f, "{}", if i != LEN - 1 { "" } else { "##" })?;
+            writeln!(f)?;
         }
-        println!("  #########");
+        write!(f, "  #########")
     }
+}
 
+impl<const LEN: usize> State<LEN> {
     fn is_final(&self) -> bool {
         self.rooms[0].is_full_of(Amphipod::A)
             && self.rooms[1].is_full_of(Amphipod::B)
@@ -198,7 +260,7 @@ impl State {
             && self.rooms[3].is_full_of(Amphipod::D)
     }
 
-    fn next(&self) -> Vec<(State, u64)> {
+    fn next(&self) -> Vec<(State<LEN>, u64)> {
         let mut next = Vec::new();
         for room_i in Amphipod::ALL {
             let room = &self.rooms[room_i.room_i()];
@@ -210,8 +272,8 @@ impl State {
 
             for i in 0..Hallway::LEN {
                 if let Some(len) = self.hallway.can_move_to_from_room(i, room_i) {
-                    let len = len + (Room::LEN - room_pos);
-                    let mut new_state = self.clone();
+                    let len = len + (LEN - room_pos);
+                    let mut new_state = *self;
                     new_state.hallway.places[i] = Some(an);
                     new_state.rooms[room_i.room_i()].amphipods[room_pos] = None;
                     next.push((new_state, len as u64 * an.energy()));
@@ -243,82 +305,39 @@ impl State {
                     unreachable!();
                 }
 
-                let mut new_state = self.clone();
+                let mut new_state = *self;
                 new_state.hallway.places[hallway_i] = None;
                 new_state.rooms[an.room_i()].amphipods[room_pos] = Some(an);
 
                 let len = ((hallway_i as i64) - (Hallway::room_to_hallway(an) as i64)).abs()
-                    + (Room::LEN as i64 - room_pos as i64);
+                    + (LEN as i64 - room_pos as i64);
 
                 next.push((new_state, an.energy() * (len as u64)));
             }
         }
 
-        // println!("for state:");
-        // self.print();
-        // println!();
-        // println!("next states:");
-        // for (state, en) in &next {
-        //     println!("en {}:", en);
-        //     state.print();
-        // }
+        trace!("for state:\n{}", self);
+        trace!("next states:");
+        for (state, en) in &next {
+            trace!("en {}:\n{}", en, state);
+        }
 
         next
     }
 }
 
-#[derive(Default)]
-struct Solution {}
-
-impl Solution {
-    fn solve(&mut self, init: &State) -> u64 {
-        // init.print();
-        let mut visited: HashMap<State, (u64, State)> = HashMap::new();
-        let mut border: BTreeSet<(u64, State)> = BTreeSet::new();
-
-        border.insert((0, init.clone()));
-        loop {
-            let (en, state) = border.pop_first().unwrap();
-            if state.is_final() {
-                println!();
-
-                let mut path = Vec::new();
-                let mut state = state;
-                path.push(state.clone());
-                while &state != init {
-                    state = visited.get(&state).unwrap().1.clone();
-                    path.push(state);
-                }
-
-                println!("path:");
-                for state in path.iter().rev() {
-                    println!();
-                    state.print();
-                }
-                return en;
-            }
-
-            // println!("iter {} {} {}", border.len(), visited.len(), en);
+fn solve<const LEN: usize>(init: &State<LEN>) -> u64 {
+    trace!("init:\n{}", init);
+    let path = search::dijkstra(*init, |state| state.next(), |state| state.is_final()).unwrap();
 
-            for (next_state, step_en) in state.next() {
-                assert!(step_en > 0);
-                match visited.get(&next_state) {
-                    None => {
-                        visited.insert(next_state.clone(), (en + step_en, state.clone()));
-                        border.insert((en + step_en, next_state));
-                    }
-                    Some(&(visited_en, _)) if visited_en >= en + step_en => {
-                        visited.insert(next_state.clone(), (en + step_en, state.clone()));
-                        border.insert((en + step_en, next_state));
-                    }
-                    Some(_) => {}
-                }
-            }
-        }
+    debug!("path:");
+    for state in &path.states {
+        debug!("{}\n", state);
     }
+    path.cost
 }
 
-fn run(input: &Input) {
+fn run<const LEN: usize>(input: &Input<LEN>) -> u64 {
     let mut counts = HashMap::new();
     for room in &input.rooms {
         for amphipod in &room.amphipods {
@@ -326,55 +345,30 @@ fn run(input: &Input) {
         }
     }
     for amphipod in &Amphipod::ALL {
-        assert_eq!(Room::LEN, counts[&amphipod]);
+        assert_eq!(LEN, counts[&amphipod]);
     }
-    let energy = Solution::default().solve(&State {
-        rooms: input.rooms.map(|room| Room::new(room)),
+    solve(&State {
+        rooms: input.rooms.map(Room::new),
         hallway: Hallway { places: [None; 11] },
-    });
-    println!("min energy: {}", energy);
-    if Room::LEN == 2 {
-        assert!(energy == 12521 || energy == 15299);
-    } else if Room::LEN == 4 {
-        assert!(energy == 44169 || energy == 47193);
-    } else {
-        unreachable!();
-    }
+    })
 }
 
-fn main() {
-    println!("Test");
-    run(&Input {
-        rooms: [
-            RoomInit {
-                amphipods: [Amphipod::A, Amphipod::D, Amphipod::D, Amphipod::B],
-            },
-            RoomInit {
-                amphipods: [Amphipod::D, Amphipod::B, Amphipod::C, Amphipod::C],
-            },
-            RoomInit {
-                amphipods: [Amphipod::C, Amphipod::A, Amphipod::B, Amphipod::B],
-            },
-            RoomInit {
-                amphipods: [Amphipod::A, Amphipod::C, Amphipod::A, Amphipod::D],
-            },
-        ],
-    });
-    println!("Real");
-    run(&Input {
-        rooms: [
-            RoomInit {
-                amphipods: [Amphipod::D, Amphipod::D, Amphipod::D, Amphipod::C],
-            },
-            RoomInit {
-                amphipods: [Amphipod::A, Amphipod::B, Amphipod::C, Amphipod::C],
-            },
-            RoomInit {
-                amphipods: [Amphipod::B, Amphipod::A, Amphipod::B, Amphipod::B],
-            },
-            RoomInit {
-                amphipods: [Amphipod::A, Amphipod::C, Amphipod::A, Amphipod::D],
-            },
-        ],
-    });
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Input<2>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Input<2>, Error> {
+        Ok(Input::parse(input)?)
+    }
+
+    fn part1(input: &Input<2>) -> Result<u64, Error> {
+        Ok(run(input))
+    }
+
+    fn part2(input: &Input<2>) -> Result<u64, Error> {
+        Ok(run(&input.unfold()))
+    }
 }
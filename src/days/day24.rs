@@ -1,13 +1,18 @@
 use std::cmp;
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::fmt;
-use std::fmt::write;
-use std::fs;
 use std::mem;
 
-use rand::Rng;
-use rand::SeedableRng;
+use crate::checked;
+use crate::checked::Overflow;
+use crate::debug;
+use crate::parse;
+use crate::parse::Line;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Error;
+use crate::Simulate;
+use crate::Solution;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum Reg {
@@ -44,13 +49,13 @@ impl Reg {
         }
     }
 
-    fn parse(s: &str) -> Reg {
+    fn parse(line: &Line, s: &str) -> Result<Reg, ParseError> {
         match s {
-            "x" => Reg::X,
-            "y" => Reg::Y,
-            "z" => Reg::Z,
-            "w" => Reg::W,
-            s => panic!("Unknown variable name: {}", s),
+            "x" => Ok(Reg::X),
+            "y" => Ok(Reg::Y),
+            "z" => Ok(Reg::Z),
+            "w" => Ok(Reg::W),
+            s => Err(line.error(s, "variable name")),
         }
     }
 }
@@ -71,10 +76,10 @@ impl fmt::Display for Arg {
 }
 
 impl Arg {
-    fn parse(s: &str) -> Arg {
+    fn parse(line: &Line, s: &str) -> Result<Arg, ParseError> {
         match s {
-            "x" | "y" | "z" | "w" => Arg::VarName(Reg::parse(s)),
-            s => Arg::Literal(s.parse::<i64>().unwrap()),
+            "x" | "y" | "z" | "w" => Ok(Arg::VarName(Reg::parse(line, s)?)),
+            s => Ok(Arg::Literal(line.parse(s, "variable name or number")?)),
         }
     }
 }
@@ -113,59 +118,68 @@ impl fmt::Display for Instr {
 }
 
 impl Instr {
-    fn parse(s: &str) -> Instr {
-        let parts: Vec<_> = s.split(" ").collect();
-        if parts[0] == "inp" {
-            assert_eq!(2, parts.len());
-            let var_name = Reg::parse(parts[1]);
-            Instr::Inp(var_name)
+    fn parse(line: &Line) -> Result<Instr, ParseError> {
+        let (opcode, s) = line.split_once(line.text, " ")?;
+        if opcode == "inp" {
+            let var_name = Reg::parse(line, s)?;
+            Ok(Instr::Inp(var_name))
         } else {
-            assert_eq!(3, parts.len());
-            let var_name = Reg::parse(parts[1]);
-            let arg = Arg::parse(parts[2]);
+            let (var_name, arg_s) = line.split_once(s, " ")?;
+            let var_name = Reg::parse(line, var_name)?;
+            let arg = Arg::parse(line, arg_s)?;
 
-            let instr = match parts[0] {
+            let instr = match opcode {
                 "add" => Instr::Add(var_name, arg),
                 "mul" => Instr::Mul(var_name, arg),
                 "div" => Instr::Div(var_name, arg),
                 "mod" => Instr::Mod(var_name, arg),
                 "eql" => Instr::Eql(var_name, arg),
-                s => panic!("Unknown instruction: {}", s),
+                s => return Err(line.error(s, "instruction")),
             };
 
+            // Division or modulo by variable is not supported.
             match instr {
-                Instr::Div(_, Arg::VarName(_)) => panic!("Division by variable not supported"),
-                Instr::Mod(_, Arg::VarName(_)) => panic!("Modulo by variable not supported"),
-                _ => {}
+                Instr::Div(_, Arg::VarName(_)) | Instr::Mod(_, Arg::VarName(_)) => {
+                    Err(line.error(arg_s, "number"))
+                }
+                _ => Ok(instr),
             }
-
-            instr
         }
     }
 
-    fn eval_bin_op(&self, reg: &mut Registers, var: Reg, arg: Arg, op: impl Fn(i64, i64) -> i64) {
+    fn eval_bin_op(
+        &self,
+        reg: &mut Registers,
+        var: Reg,
+        arg: Arg,
+        op: impl Fn(i64, i64) -> Result<i64, Overflow>,
+    ) -> Result<(), Overflow> {
         let arg0 = reg.get(var);
         let arg1 = match arg {
             Arg::VarName(var) => reg.get(var),
             Arg::Literal(value) => value,
         };
-        let res = op(arg0, arg1);
+        let res = op(arg0, arg1)?;
         reg.set(var, res);
+        Ok(())
     }
 
-    fn eval(&self, reg: &mut Registers, input: &mut Input) {
+    fn eval(&self, reg: &mut Registers, input: &mut Input) -> Result<(), Overflow> {
         match self {
-            Instr::Inp(var) => reg.set(*var, input.next()),
-            Instr::Add(var, arg) => self.eval_bin_op(reg, *var, *arg, |a, b| a + b),
-            Instr::Mul(var, arg) => self.eval_bin_op(reg, *var, *arg, |a, b| a * b),
-            Instr::Div(var, arg) => self.eval_bin_op(reg, *var, *arg, |a, b| a / b),
+            Instr::Inp(var) => {
+                reg.set(*var, input.next());
+                Ok(())
+            }
+            Instr::Add(var, arg) => self.eval_bin_op(reg, *var, *arg, checked::add),
+            Instr::Mul(var, arg) => self.eval_bin_op(reg, *var, *arg, checked::mul),
+            Instr::Div(var, arg) => self.eval_bin_op(reg, *var, *arg, |a, b| Ok(a / b)),
             Instr::Mod(var, arg) => self.eval_bin_op(reg, *var, *arg, |a, b| {
                 assert!(a >= 0);
                 assert!(b > 0);
-                a % b
+                Ok(a % b)
             }),
             Instr::Eql(var, arg) => {
-                self.eval_bin_op(reg, *var, *arg, |a, b| if a == b { 1 } else { 0 })
+                self.eval_bin_op(reg, *var, *arg, |a, b| Ok(if a == b { 1 } else { 0 }))
             }
         }
     }
@@ -193,11 +207,23 @@ impl Instr {
     }
 }
 
-#[derive(Default, Eq, PartialEq, Debug)]
+#[derive(Default, Eq, PartialEq, Debug, Clone)]
 struct Registers {
     registers: [i64; 4],
 }
 
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, reg) in [Reg::W, Reg::X, Reg::Y, Reg::Z].iter().enumerate() {
+            if i != 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}={}", reg, self.get(*reg))?;
+        }
+        Ok(())
+    }
+}
+
 impl Registers {
     fn get(&self, register: Reg) -> i64 {
         self.registers[register.index()]
@@ -208,14 +234,16 @@ impl Registers {
     }
 }
 
+#[derive(Clone)]
 struct Input {
     numbers: Vec<i64>,
     pos: usize,
 }
 
 impl Input {
+    #[allow(dead_code)]
     fn parse_digits_from_dec(s: &str) -> Input {
-        Self::parse_digits(&s.to_string())
+        Self::parse_digits(s)
     }
 
     fn parse_digits(input: &str) -> Input {
@@ -237,12 +265,14 @@ impl Input {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
 struct Range {
     from: i64,
     to_incl: i64,
 }
 
+#[allow(dead_code)]
 impl Range {
     fn new(from: i64, to_incl: i64) -> Range {
         assert!(from <= to_incl);
@@ -260,6 +290,7 @@ impl Range {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Eq, PartialEq, Debug, Clone)]
 enum Expr {
     Literal(i64),
@@ -271,6 +302,7 @@ enum Expr {
     Eql(Box<Expr>, Box<Expr>),
 }
 
+#[allow(dead_code)]
 impl Expr {
     fn range(&self) -> Option<Range> {
         match self {
@@ -323,7 +355,6 @@ impl Expr {
                     None
                 }
             }
-            _ => None,
         }
     }
 
@@ -359,10 +390,7 @@ impl Expr {
     }
 
     fn is_input(&self) -> bool {
-        match self {
-            Expr::Inp(_) => true,
-            _ => false,
-        }
+        matches!(self, Expr::Inp(_))
     }
 
     fn eql(a: Expr, b: Expr) -> Expr {
@@ -371,7 +399,7 @@ impl Expr {
         }
         if let (Some(a_range), Some(b_range)) = (a.range(), b.range()) {
             if !Range::may_intersect(&a_range, &b_range) {
-                // println!("cannot {:?} {:?}", a_range, b_range);
+                trace!("cannot be equal {:?} {:?}", a_range, b_range);
                 return Expr::Literal(0);
             }
         }
@@ -432,8 +460,8 @@ impl Expr {
 
     fn print_tree_impl(&self, indent: usize) {
         match self {
-            Expr::Literal(value) => println!("{:>width$}{}", "", value, width = indent),
-            Expr::Inp(i) => println!("{:>width$}input[{}]", "", i, width = indent),
+            Expr::Literal(value) => trace!("{:>width$}{}", "", value, width = indent),
+            Expr::Inp(i) => trace!("{:>width$}input[{}]", "", i, width = indent),
             e => {
                 let (op, a, b) = match e {
                     Expr::Literal(..) | Expr::Inp(..) => unreachable!(),
@@ -443,7 +471,7 @@ impl Expr {
                     Expr::Mod(a, b) => ("%", a, b),
                     Expr::Eql(a, b) => ("==", a, b),
                 };
-                println!("{:>width$}{} ({:?})", "", op, self.range(), width = indent);
+                trace!("{:>width$}{} ({:?})", "", op, self.range(), width = indent);
                 a.print_tree_impl(indent + 2);
                 b.print_tree_impl(indent + 2);
             }
@@ -496,6 +524,7 @@ impl fmt::Display for Expr {
     }
 }
 
+#[allow(dead_code)]
 enum ExprBool {
     Eq0(Expr),
     Or(Box<ExprBool>, Box<ExprBool>),
@@ -504,25 +533,18 @@ enum ExprBool {
     False,
 }
 
+#[allow(dead_code)]
 impl ExprBool {
     fn is_true(&self) -> bool {
-        match self {
-            ExprBool::True => true,
-            _ => false,
-        }
+        matches!(self, ExprBool::True)
     }
 
     fn is_false(&self) -> bool {
-        match self {
-            ExprBool::False => true,
-            _ => false,
-        }
+        matches!(self, ExprBool::False)
     }
 
     fn or(a: ExprBool, b: ExprBool) -> ExprBool {
-        if a.is_true() {
-            ExprBool::True
-        } else if b.is_true() {
+        if a.is_true() || b.is_true() {
             ExprBool::True
         } else if a.is_false() {
             b
@@ -578,14 +600,13 @@ impl ExprBool {
             Expr::Div(a, b) => {
                 if let Some(b_range) = b.range() {
                     if b_range.from != 0 {
-                        return Self::eq_0(a);
+                        Self::eq_0(a)
                     } else {
                         panic!();
                     }
                 } else {
                     panic!();
                 }
-                ExprBool::Eq0(expr.clone())
             }
             expr => ExprBool::Eq0(expr.clone()),
         }
@@ -593,18 +614,18 @@ impl ExprBool {
 
     fn print_tree_impl(&self, indent: usize) {
         match self {
-            ExprBool::True => println!("{:>width$}{}", "", "true", width = indent),
-            ExprBool::False => println!("{:>width$}{}", "", "false", width = indent),
+            ExprBool::True => trace!("{:>width$}true", "", width = indent),
+            ExprBool::False => trace!("{:>width$}false", "", width = indent),
             ExprBool::Eq0(e) => {
-                println!("{:>width$}{}", "", "== 0", width = indent);
+                trace!("{:>width$}== 0", "", width = indent);
                 e.print_tree_impl(indent + 2);
             }
             ExprBool::Not(e) => {
-                println!("{:>width$}{}", "", "!", width = indent);
+                trace!("{:>width$}!", "", width = indent);
                 e.print_tree_impl(indent + 2);
             }
             ExprBool::Or(a, b) => {
-                println!("{:>width$}{}", "", "||", width = indent);
+                trace!("{:>width$}||", "", width = indent);
                 a.print_tree_impl(indent + 2);
                 b.print_tree_impl(indent + 2);
             }
@@ -648,6 +669,7 @@ struct ProgramBuilder {
     known_regs: KnownRegs,
 }
 
+#[derive(Clone)]
 struct Program {
     instrs: Vec<Instr>,
 }
@@ -686,30 +708,30 @@ impl ProgramBuilder {
     }
 }
 
-impl Program {
-    fn print(&self) {
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for instr in &self.instrs {
-            println!("{}", instr);
+            writeln!(f, "{}", instr)?;
         }
+        Ok(())
     }
+}
 
-    fn parse(prog: &str) -> Program {
-        let instrs: Vec<_> = prog.lines().map(|l| Instr::parse(l)).collect();
-        Program { instrs }
-    }
-
-    fn parse_file(filename: &str) -> Program {
-        let content = fs::read_to_string(filename).unwrap();
-        Program::parse(&content)
+impl Program {
+    fn parse(prog: &str) -> Result<Program, ParseError> {
+        let instrs = parse::lines(prog)
+            .map(|line| Instr::parse(&line))
+            .collect::<Result<_, _>>()?;
+        Ok(Program { instrs })
     }
 
-    fn eval(&self, mut input: Input) -> Registers {
+    fn eval(&self, mut input: Input) -> Result<Registers, Overflow> {
         let mut reg = Registers::default();
         for instr in &self.instrs {
-            instr.eval(&mut reg, &mut input);
+            instr.eval(&mut reg, &mut input)?;
         }
         assert!(input.eof());
-        reg
+        Ok(reg)
     }
 
     fn opt(&self) -> (Program, KnownRegs) {
@@ -787,7 +809,7 @@ impl Program {
                 };
                 instrs.set(reg, r);
             } else {
-                instrs.push(instr.clone());
+                instrs.push(instr);
                 instrs.known_regs.regs[reg.index()] = None;
             }
         }
@@ -799,6 +821,7 @@ impl Program {
         )
     }
 
+    #[allow(dead_code)]
     fn opt_first_inp(&self, d: i64) -> (Program, KnownRegs) {
         let (index, reg) = self
             .instrs
@@ -818,14 +841,15 @@ impl Program {
         Program { instrs }.opt()
     }
 
+    #[allow(dead_code)]
     fn to_expr(&self) -> Expr {
         let mut i = 0;
         let mut regs = [(); Reg::COUNT].map(|_| Expr::Literal(0));
         for (ip, &instr) in self.instrs.iter().enumerate() {
-            println!("{}: {}", ip, instr);
+            trace!("{}: {}", ip, instr);
             if let Instr::Inp(reg) = instr {
                 let expr = Expr::Inp(i);
-                println!("{} = {}", reg, expr);
+                trace!("{} = {}", reg, expr);
                 regs[reg.index()] = expr;
                 i += 1;
                 continue;
@@ -851,9 +875,9 @@ impl Program {
                 Instr::Mod(..) => Expr::Mod(reg_expr, arg_expr),
                 Instr::Eql(..) => Expr::Eql(reg_expr, arg_expr),
             };
-            println!("{} = {}", reg, expr);
+            trace!("{} = {}", reg, expr);
             let expr = expr.opt();
-            println!("{} = {}", reg, expr);
+            trace!("{} = {}", reg, expr);
             // if expr.to_string().len() > 1000 {
             //     panic!();
             // }
@@ -863,29 +887,13 @@ impl Program {
     }
 }
 
-fn test_program(prog: &str, input: &str, expected: Registers) {
-    let prog = prog.replace("; ", "\n");
-    let prog = Program::parse(&prog);
-    let input = Input::parse_digits(input);
-    let reg = prog.eval(input);
-    assert_eq!(expected, reg);
-}
-
-fn test() {
-    test_program(
-        "inp x; mul x -1",
-        "8",
-        Registers {
-            registers: [-8, 0, 0, 0],
-        },
-    );
-}
-
+#[allow(dead_code)]
 struct IncOpt {
     i: usize,
     digits: Vec<u32>,
 }
 
+#[allow(dead_code)]
 impl IncOpt {
     fn run(&mut self, prog: &Program) {
         if self.digits.len() == 14 {
@@ -896,16 +904,10 @@ impl IncOpt {
                 let (prog, known_regs) = prog.opt_first_inp(d as i64);
                 let z = known_regs.regs[Reg::Z.index()];
                 if let Some(z) = z {
-                    // println!("ZZZZ");
-                    // prog.print();
-                    // panic!();
-                    if self.i % 10_000 == 0 || self.digits.len() != 14 {
-                        println!();
-                        for digit in &self.digits {
-                            print!("{}", digit);
-                        }
-                        println!();
-                        println!("{}", z);
+                    trace!("known z after {:?}:\n{}", self.digits, prog);
+                    if self.i.is_multiple_of(10_000) || self.digits.len() != 14 {
+                        let digits: String = self.digits.iter().map(|d| d.to_string()).collect();
+                        debug!("{}\n{}", digits, z);
                     }
                     self.i += 1;
 
@@ -925,6 +927,7 @@ impl IncOpt {
     }
 }
 
+#[allow(dead_code)]
 fn inc_opt(prog: &Program) {
     IncOpt {
         digits: Vec::new(),
@@ -933,23 +936,23 @@ fn inc_opt(prog: &Program) {
     .run(prog);
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Block {
     params: [i64; 3],
 }
 
 impl Block {
-    fn new(instrs: &[Instr]) -> Block {
+    /// `None` if the block does not have the shape of the puzzle blocks.
+    fn new(instrs: &[Instr]) -> Option<Block> {
         match instrs {
-            [Instr::Inp(Reg::W), Instr::Mul(Reg::X, Arg::Literal(0)), Instr::Add(Reg::X, Arg::VarName(Reg::Z)), Instr::Mod(Reg::X, Arg::Literal(26)), Instr::Div(Reg::Z, Arg::Literal(p0)), Instr::Add(Reg::X, Arg::Literal(p1)), Instr::Eql(Reg::X, Arg::VarName(Reg::W)), Instr::Eql(Reg::X, Arg::Literal(0)), Instr::Mul(Reg::Y, Arg::Literal(0)), Instr::Add(Reg::Y, Arg::Literal(25)), Instr::Mul(Reg::Y, Arg::VarName(Reg::X)), Instr::Add(Reg::Y, Arg::Literal(1)), Instr::Mul(Reg::Z, Arg::VarName(Reg::Y)), Instr::Mul(Reg::Y, Arg::Literal(0)), Instr::Add(Reg::Y, Arg::VarName(Reg::W)), Instr::Add(Reg::Y, Arg::Literal(p2)), Instr::Mul(Reg::Y, Arg::VarName(Reg::X)), Instr::Add(Reg::Z, Arg::VarName(Reg::Y))] =>
+            [Instr::Inp(Reg::W), Instr::Mul(Reg::X, Arg::Literal(0)), Instr::Add(Reg::X, Arg::VarName(Reg::Z)), Instr::Mod(Reg::X, Arg::Literal(26)), Instr::Div(Reg::Z, Arg::Literal(p0)), Instr::Add(Reg::X, Arg::Literal(p1)), Instr::Eql(Reg::X, Arg::VarName(Reg::W)), Instr::Eql(Reg::X, Arg::Literal(0)), Instr::Mul(Reg::Y, Arg::Literal(0)), Instr::Add(Reg::Y, Arg::Literal(25)), Instr::Mul(Reg::Y, Arg::VarName(Reg::X)), Instr::Add(Reg::Y, Arg::Literal(1)), Instr::Mul(Reg::Z, Arg::VarName(Reg::Y)), Instr::Mul(Reg::Y, Arg::Literal(0)), Instr::Add(Reg::Y, Arg::VarName(Reg::W)), Instr::Add(Reg::Y, Arg::Literal(p2)), Instr::Mul(Reg::Y, Arg::VarName(Reg::X)), Instr::Add(Reg::Z, Arg::VarName(Reg::Y))]
+                if (*p0 == 1 || *p0 == 26) && *p2 >= 4 =>
             {
-                assert!(*p0 == 1 || *p0 == 26);
-                assert!(*p2 >= 4);
-                Block {
+                Some(Block {
                     params: [*p0, *p1, *p2],
-                }
+                })
             }
-            _ => panic!(),
+            _ => None,
         }
     }
 
@@ -987,8 +990,8 @@ impl Block {
         // Instr::Mul(Reg::Y, Arg::VarName(Reg::X)),
         let y = y * x;
         // Instr::Add(Reg::Z, Arg::VarName(Reg::Y)),
-        let z = z + y;
-        z
+
+        z + y
     }
 
     fn eval(&self, z: i64, w: i64) -> i64 /* z */ {
@@ -1016,7 +1019,6 @@ impl Block {
             // Always > 0
             zd * 26 + w + self.params[2]
         } else {
-            // println!("XXX {} {}", z, zd);
             zd
         };
 
@@ -1033,20 +1035,21 @@ impl Block {
     }
 }
 
+#[allow(dead_code)]
 struct Blocks {
     blocks: Vec<Block>,
 }
 
+#[allow(dead_code)]
 impl Blocks {
     fn eval(&self, ws: &[i64]) -> i64 {
         assert_eq!(ws.len(), self.blocks.len());
 
         let mut z = 0;
 
-        // println!("start");
         for (w, block) in ws.iter().zip(&self.blocks) {
             z = block.eval(z, *w);
-            // println!("z = {}", z);
+            trace!("z = {}", z);
         }
 
         z
@@ -1061,7 +1064,10 @@ impl Blocks {
     }
 }
 
-struct BlocksCalc {
+#[derive(Clone)]
+pub struct BlocksCalc {
+    /// Original program, for the REPL.
+    program: Program,
     blocks: Vec<Block>,
     block_lower_bound_matches_by_index_by_z: HashMap<(usize, i64), bool>,
 
@@ -1083,7 +1089,6 @@ impl BlocksCalc {
 
         let new_z = self.blocks[digit].lower_bound_for_z(z);
         let r = if digit == self.blocks.len() - 1 {
-            // println!("last digit new_z {}", new_z);
             new_z <= 0
         } else {
             self.lower_bound_matches(digit + 1, new_z)
@@ -1102,216 +1107,302 @@ impl BlocksCalc {
             .join("")
     }
 
-    fn run_up(&mut self, z: i64) {
+    fn run_up(&mut self, z: i64) -> bool {
         if !self.lower_bound_matches(self.digits.len(), z) {
-            // println!("{}", self.digits_str());
-            // panic!();
-            return;
+            trace!("pruned {}", self.digits_str());
+            return false;
         }
 
         for d in (1..=9).rev() {
             self.digits.push(d);
             let new_z = self.blocks[self.digits.len() - 1].eval(z, d);
             if self.digits.len() == self.blocks.len() {
-                if self.progress % 10_000_000 == 0 {
-                    println!("{} {}", self.digits_str(), new_z);
+                if self.progress.is_multiple_of(10_000_000) {
+                    debug!("{} {}", self.digits_str(), new_z);
                 }
-                assert_ne!(new_z, 0, "at: {}", self.digits_str());
                 self.progress += 1;
-            } else {
-                self.run_up(new_z);
+                if new_z == 0 {
+                    return true;
+                }
+            } else if self.run_up(new_z) {
+                return true;
             }
             self.digits.pop().unwrap();
         }
+        false
     }
 
-    fn run_down(&mut self, z: i64) {
+    fn run_down(&mut self, z: i64) -> bool {
         self.progress += 1;
-        if self.progress % 1000_000 == 0 {
-            println!("{}", self.digits_str());
+        if self.progress.is_multiple_of(1_000_000) {
+            debug!("{}", self.digits_str());
         }
 
         if !self.lower_bound_matches(self.digits.len(), z) {
-            // println!("{}", self.digits_str());
-            // panic!();
-            return;
+            trace!("pruned {}", self.digits_str());
+            return false;
         }
 
-        for d in (1..=9) {
+        for d in 1..=9 {
             self.digits.push(d);
             let new_z = self.blocks[self.digits.len() - 1].eval(z, d);
             if self.digits.len() == self.blocks.len() {
-                // if self.progress % 1 == 0 {
-                println!("{} {}", self.digits_str(), new_z);
-                // }
-                assert_ne!(new_z, 0, "at: {}", self.digits_str());
+                trace!("{} {}", self.digits_str(), new_z);
                 self.progress += 1;
-            } else {
-                self.run_down(new_z);
+                if new_z == 0 {
+                    return true;
+                }
+            } else if self.run_down(new_z) {
+                return true;
             }
             self.digits.pop().unwrap();
         }
+        false
     }
 }
 
-fn run() {
-    let prog_orig = Program::parse_file("day24-input.txt");
+/// Model number run by the program before and after optimization, to check
+/// the optimizer.
+const CHECK_MODEL_NUMBER: &str = "13579246899999";
 
-    println!("program {}:", prog_orig.instrs.len());
-    // prog_orig.print();
+/// One block per digit of the model number.
+const BLOCKS: usize = 14;
 
-    let prog = prog_orig.opt().0;
-    println!();
-    println!(
-        "opt {} (from {}):",
-        prog.instrs.len(),
-        prog_orig.instrs.len()
-    );
-    // prog.print();
-    // let prog = prog_orig;
-
-    println!();
-
-    let z = prog.eval(Input::parse_digits("13579246899999")).get(Reg::Z);
-    println!("z = {}", z);
-    assert_eq!(
-        z,
-        prog_orig
-            .eval(Input::parse_digits("13579246899999"))
-            .get(Reg::Z)
-    );
+fn parse_blocks(input: &str) -> Result<BlocksCalc, Error> {
+    let prog_orig = Program::parse(input)?;
 
-    let z = prog.eval(Input::parse_digits("99999999999999")).get(Reg::Z);
-    println!("z = {}", z);
-    assert_eq!(
-        z,
-        prog_orig
-            .eval(Input::parse_digits("99999999999999"))
-            .get(Reg::Z)
-    );
+    debug!("program {}:", prog_orig.instrs.len());
+    trace!("{}", prog_orig);
 
-    let mut blocks = Vec::new();
-    for instr in &prog_orig.instrs {
+    // Blocks starting with `inp`, with the line of the `inp`.
+    let mut blocks: Vec<(usize, Vec<Instr>)> = Vec::new();
+    for (i, instr) in prog_orig.instrs.iter().enumerate() {
         if let Instr::Inp(..) = instr {
-            blocks.push(Vec::new());
+            blocks.push((i + 1, Vec::new()));
+        }
+        match blocks.last_mut() {
+            Some((_, block)) => block.push(*instr),
+            None => return Err(ParseError::new(i + 1, 1, "`inp`").into()),
         }
-        blocks.last_mut().unwrap().push(instr.clone());
     }
 
-    let part_sigs: Vec<Vec<Opcode>> = blocks
-        .iter()
-        .map(|part| part.iter().map(|instr| instr.opcode()).collect())
-        .collect::<Vec<_>>();
-
-    let sig = HashSet::<Vec<Opcode>>::from_iter(part_sigs);
-    assert_eq!(1, sig.len());
-    let sig = sig.into_iter().next().unwrap();
-
-    println!("part len: {}", sig.len());
-    println!("sig:      {:?}", sig);
-
-    let blocks = blocks
-        .iter()
-        .map(|part| Block::new(part))
-        .collect::<Vec<_>>();
+    if let Some((_, first)) = blocks.first() {
+        let sig: Vec<Opcode> = first.iter().map(Instr::opcode).collect();
+        debug!("part len: {}", sig.len());
+        debug!("sig:      {:?}", sig);
+    }
 
-    assert_eq!(14, blocks.len());
+    // Every block has the shape `Block::new` matches.
+    let expected = format!("{} `inp` blocks of the same shape", BLOCKS);
+    let mut parsed = Vec::new();
+    for (line, part) in &blocks {
+        match Block::new(part) {
+            Some(block) if parsed.len() < BLOCKS => parsed.push(block),
+            _ => return Err(ParseError::new(*line, 1, expected).into()),
+        }
+    }
+    if parsed.len() < BLOCKS {
+        let line = prog_orig.instrs.len() + 1;
+        return Err(ParseError::new(line, 1, expected).into());
+    }
+    let blocks = parsed;
 
     for block in &blocks {
-        println!("block: {:?}", block);
+        debug!("block: {:?}", block);
     }
 
-    let blocks = Blocks { blocks };
+    let prog = prog_orig.opt().0;
+    debug!(
+        "opt {} (from {}):",
+        prog.instrs.len(),
+        prog_orig.instrs.len()
+    );
+    trace!("{}", prog);
+
+    let z = prog
+        .eval(Input::parse_digits(CHECK_MODEL_NUMBER))?
+        .get(Reg::Z);
+    debug!("z = {}", z);
+    let expected_z = prog_orig
+        .eval(Input::parse_digits(CHECK_MODEL_NUMBER))?
+        .get(Reg::Z);
+    if z != expected_z {
+        return Err(ParseError::new(1, 1, "program which the optimizer handles").into());
+    }
+
+    Ok(BlocksCalc {
+        program: prog_orig,
+        blocks,
+        block_lower_bound_matches_by_index_by_z: HashMap::new(),
+        digits: Vec::new(),
+        progress: 0,
+    })
+}
 
-    assert_eq!(76981240, blocks.eval_str("13579246899999"));
-    assert_eq!(4545011352, blocks.eval_str("99999999999999"));
+fn no_model_number() -> Error {
+    Error::NoAnswer("no valid model number".to_owned())
+}
 
-    println!("AAAAAAA");
-    println!("{}", blocks.eval_str("99919692496939"));
-    println!("AAAAAAA");
-    println!("{}", blocks.eval_str("81914111161714"));
+pub struct Day24;
 
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+impl Solution for Day24 {
+    type Input = BlocksCalc;
+    type Answer1 = String;
+    type Answer2 = String;
 
-    // let mut i: u64 = 0;
-    // loop {
-    //     let v = rng.gen_range(11111_11111_1111u64..=99999_99999_9999);
-    //     let s = v.to_string();
-    //     let r = blocks.eval_str(&s);
-    //     if i % 100_000 == 0 {
-    //         println!("{} {} {}", i, v, r);
-    //     }
-    //     assert!(r != 0);
-    //     i += 1;
-    // }
+    fn parse(input: &str) -> Result<BlocksCalc, Error> {
+        parse_blocks(input)
+    }
 
-    // for i in (11111_11111_1111u64..=99999_99999_9999).rev() {
-    //     let s = i.to_string();
-    //     let r = blocks.eval_str(&s);
-    //     println!("{} {}", i, r);
-    // }
+    fn part1(calc: &BlocksCalc) -> Result<String, Error> {
+        let mut calc = calc.clone();
+        if !calc.run_up(0) {
+            return Err(no_model_number());
+        }
+        Ok(calc.digits_str())
+    }
 
-    BlocksCalc {
-        blocks: blocks.blocks,
-        block_lower_bound_matches_by_index_by_z: HashMap::new(),
-        digits: Vec::new(),
-        progress: 0,
+    fn part2(calc: &BlocksCalc) -> Result<String, Error> {
+        let mut calc = calc.clone();
+        if !calc.run_down(0) {
+            return Err(no_model_number());
+        }
+        Ok(calc.digits_str())
     }
-    .run_down(0);
-
-    // inc_opt(&prog);
-
-    // println!();
-    // let expr = prog_orig.to_expr();
-    // println!("{}", expr);
-    // println!();
-    // // expr.print_tree();
-    // println!();
-    // println!("{}", expr.short());
-    //
-    // println!();
-    // let (a, b) = expr.args();
-    // println!(".0 = {}", a.short());
-    // println!(".1 = {}", b.short());
-    //
-    // println!();
-    // let (a, b) = a.args();
-    // println!(".0.0 = {}", a.short());
-    // println!(".0.1 = {}", b.short());
-    //
-    // let eqz = ExprBool::eq_0(&expr);
-    //
-    // println!("{}", eqz);
-    // println!();
-    // println!("eqz:");
-    // println!();
-    // eqz.print_tree();
-    // println!();
-    // println!("EOF");
-
-    return;
-    // let prog = prog_orig;
-
-    let mut i = 0;
-    for n in (0..=99999_99999_9999u64).rev() {
-        let input = Input::parse_digits_from_dec(&n.to_string());
-        if input.numbers.contains(&0) {
-            continue;
+}
+
+/// ALU running the original program one instruction at a time.
+#[derive(Clone)]
+pub struct Alu {
+    /// Index of the next instruction.
+    pc: usize,
+    last: Option<Instr>,
+    registers: Registers,
+    input: Input,
+}
+
+impl fmt::Display for Alu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.last {
+            Some(instr) => writeln!(f, "{:>3}: {}", self.pc - 1, instr)?,
+            None => writeln!(f, "start")?,
         }
-        let z = prog.eval(input).get(Reg::Z);
-        if z == 0 {
-            println!("n = {}", n);
-            break;
+        writeln!(f, "{}", self.registers)?;
+        write!(f, "input: ")?;
+        for (i, n) in self.input.numbers.iter().enumerate() {
+            if i == self.input.pos {
+                write!(f, "|")?;
+            }
+            write!(f, "{}", n)?;
         }
-        if i % 100_000 == 0 {
-            println!("i = {}, n = {}", i, n);
+        Ok(())
+    }
+}
+
+impl Simulate for Day24 {
+    type State = Alu;
+
+    /// Runs the largest model number, whether it is valid or not.
+    fn start(_calc: &BlocksCalc) -> Alu {
+        Alu {
+            pc: 0,
+            last: None,
+            registers: Registers::default(),
+            input: Input::parse_digits("99999999999999"),
         }
-        i += 1;
+    }
+
+    fn step(calc: &BlocksCalc, alu: &Alu) -> Result<Option<Alu>, Error> {
+        let instr = match calc.program.instrs.get(alu.pc) {
+            Some(instr) => *instr,
+            None => return Ok(None),
+        };
+        let mut alu = alu.clone();
+        instr.eval(&mut alu.registers, &mut alu.input)?;
+        alu.pc += 1;
+        alu.last = Some(instr);
+        Ok(Some(alu))
     }
 }
 
-fn main() {
-    test();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_program(prog: &str, input: &str, expected: Registers) {
+        let prog = prog.replace("; ", "\n");
+        let prog = Program::parse(&prog).unwrap();
+        let input = Input::parse_digits(input);
+        let reg = prog.eval(input).unwrap();
+        assert_eq!(expected, reg);
+    }
+
+    #[test]
+    fn test() {
+        test_program(
+            "inp x; mul x -1",
+            "8",
+            Registers {
+                registers: [-8, 0, 0, 0],
+            },
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let prog = Program::parse("add z 9223372036854775807\nadd z 1").unwrap();
+        checked::set_test_mode(Some(true));
+        assert_eq!(
+            "arithmetic overflow: 9223372036854775807 + 1",
+            prog.eval(Input::parse_digits("")).unwrap_err().to_string()
+        );
+        checked::set_test_mode(None);
+    }
+
+    fn block(p0: i64, p1: i64, p2: i64) -> String {
+        format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+             mul y x\nadd z y\n",
+            p0, p1, p2
+        )
+    }
 
-    run();
+    #[test]
+    fn unsupported_program() {
+        let expected = "14 `inp` blocks of the same shape";
+        assert_eq!(
+            Some(Error::Parse(ParseError::new(1, 1, "`inp`"))),
+            Day24::parse("add x 1\n").err()
+        );
+        assert_eq!(
+            Some(Error::Parse(ParseError::new(19, 1, expected))),
+            Day24::parse(&block(1, 10, 5)).err()
+        );
+        let prog = block(1, 10, 5) + "inp w\nadd x 1\n";
+        assert_eq!(
+            Some(Error::Parse(ParseError::new(19, 1, expected))),
+            Day24::parse(&prog).err()
+        );
+        let prog = block(1, 10, 5).repeat(15);
+        assert_eq!(
+            Some(Error::Parse(ParseError::new(14 * 18 + 1, 1, expected))),
+            Day24::parse(&prog).err()
+        );
+    }
+
+    #[test]
+    fn no_model_number() {
+        // One block pops a digit off `z`, after 13 which push one.
+        let prog = block(1, 10, 5).repeat(13) + &block(26, 10, 5);
+        let calc = Day24::parse(&prog).unwrap();
+        assert_eq!(
+            "no answer: no valid model number",
+            Day24::part1(&calc).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "no answer: no valid model number",
+            Day24::part2(&calc).unwrap_err().to_string()
+        );
+    }
 }
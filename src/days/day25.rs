@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::grid::Edges;
+use crate::grid::Grid;
+use crate::image::Frames;
+use crate::image::Image;
+use crate::image::Rgb;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::Animate;
+use crate::Error;
+use crate::Simulate;
+use crate::Solution;
+
+#[derive(Clone, Debug, Copy, Eq, PartialEq, Default)]
+enum Loc {
+    #[default]
+    Empty,
+    South,
+    East,
+}
+
+impl fmt::Display for Loc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Loc::Empty => write!(f, "."),
+            Loc::South => write!(f, "v"),
+            Loc::East => write!(f, ">"),
+        }
+    }
+}
+
+impl Loc {
+    fn parse(c: char) -> Option<Loc> {
+        match c {
+            '.' => Some(Loc::Empty),
+            'v' => Some(Loc::South),
+            '>' => Some(Loc::East),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<Loc>,
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Map {
+    fn parse(s: &str) -> Result<Map, ParseError> {
+        let grid = Grid::from_chars(s, "`.`, `v` or `>`", Loc::parse)?;
+        Ok(Map { grid })
+    }
+
+    /// Move every `herd` cucumber by `offset` if the target is empty.
+    fn step_herd(&self, herd: Loc, offset: (isize, isize)) -> (Map, bool) {
+        let mut any_moved = false;
+        let mut grid = Grid::new(self.grid.rows(), self.grid.cols(), Loc::Empty);
+        for (pos, loc) in self.grid.iter() {
+            if *loc == herd {
+                let next = self.grid.offset(pos, offset, Edges::Wrap).unwrap();
+                let can_move = self.grid[next] == Loc::Empty;
+                grid[if can_move { next } else { pos }] = herd;
+                any_moved |= can_move;
+            } else if *loc != Loc::Empty {
+                grid[pos] = *loc;
+            }
+        }
+        (Map { grid }, any_moved)
+    }
+
+    fn step_east(&self) -> (Map, bool) {
+        self.step_herd(Loc::East, (0, 1))
+    }
+
+    fn step_south(&self) -> (Map, bool) {
+        self.step_herd(Loc::South, (1, 0))
+    }
+
+    fn image(&self) -> Image {
+        Image::from_grid(&self.grid, |loc| match loc {
+            Loc::Empty => Rgb::BLACK,
+            Loc::East => Rgb(255, 140, 0),
+            Loc::South => Rgb(0, 160, 255),
+        })
+    }
+
+    fn step(&self) -> (Map, bool) {
+        let map = self;
+        let (map, m_e) = map.step_east();
+        trace!("after east:\n{}", map.grid);
+        let (map, m_s) = map.step_south();
+        (map, m_e || m_s)
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Map, Error> {
+        Ok(Map::parse(input)?)
+    }
+
+    fn part1(map: &Map) -> Result<usize, Error> {
+        let mut map = map.clone();
+        for i in 0.. {
+            trace!("step {}:\n{}", i, map.grid);
+            let (new_map, moved) = map.step();
+            if !moved {
+                return Ok(i + 1);
+            }
+            map = new_map;
+        }
+        unreachable!()
+    }
+
+    fn part2(_map: &Map) -> Result<usize, Error> {
+        Err(Error::NoSuchPart { day: 25, part: 2 })
+    }
+}
+
+impl Simulate for Day25 {
+    type State = Map;
+
+    fn start(map: &Map) -> Map {
+        map.clone()
+    }
+
+    /// Over when the sea cucumbers stop moving.
+    fn step(_input: &Map, map: &Map) -> Result<Option<Map>, Error> {
+        let (map, moved) = map.step();
+        Ok(if moved { Some(map) } else { None })
+    }
+}
+
+impl Animate for Day25 {
+    /// Steps until the sea cucumbers stop moving.
+    fn animate(map: &Map, frames: &mut Frames) -> Result<(), Error> {
+        let mut map = map.clone();
+        frames.push(&map.image())?;
+        loop {
+            let (new_map, moved) = map.step();
+            if !moved {
+                return Ok(());
+            }
+            map = new_map;
+            frames.push(&map.image())?;
+        }
+    }
+}
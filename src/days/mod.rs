@@ -0,0 +1,167 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use std::fmt;
+use std::io::BufRead;
+use std::io::Write;
+use std::str::FromStr;
+
+use crate::image::Frames;
+use crate::repl::Repl;
+use crate::Animate;
+use crate::Error;
+use crate::Simulate;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Part, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub const DAYS: u32 = 25;
+
+/// Operation generic over the solution type, applied with `with_solution`.
+pub trait SolutionFn {
+    type Output;
+
+    fn call<S: Solution>(self) -> Self::Output;
+}
+
+pub fn with_solution<F: SolutionFn>(day: u32, f: F) -> Result<F::Output, Error> {
+    match day {
+        1 => Ok(f.call::<day01::Day01>()),
+        2 => Ok(f.call::<day02::Day02>()),
+        3 => Ok(f.call::<day03::Day03>()),
+        4 => Ok(f.call::<day04::Day04>()),
+        5 => Ok(f.call::<day05::Day05>()),
+        6 => Ok(f.call::<day06::Day06>()),
+        7 => Ok(f.call::<day07::Day07>()),
+        8 => Ok(f.call::<day08::Day08>()),
+        9 => Ok(f.call::<day09::Day09>()),
+        10 => Ok(f.call::<day10::Day10>()),
+        11 => Ok(f.call::<day11::Day11>()),
+        12 => Ok(f.call::<day12::Day12>()),
+        13 => Ok(f.call::<day13::Day13>()),
+        14 => Ok(f.call::<day14::Day14>()),
+        15 => Ok(f.call::<day15::Day15>()),
+        16 => Ok(f.call::<day16::Day16>()),
+        17 => Ok(f.call::<day17::Day17>()),
+        18 => Ok(f.call::<day18::Day18>()),
+        19 => Ok(f.call::<day19::Day19>()),
+        20 => Ok(f.call::<day20::Day20>()),
+        21 => Ok(f.call::<day21::Day21>()),
+        22 => Ok(f.call::<day22::Day22>()),
+        23 => Ok(f.call::<day23::Day23>()),
+        24 => Ok(f.call::<day24::Day24>()),
+        25 => Ok(f.call::<day25::Day25>()),
+        _ => Err(Error::NoSuchDay(day)),
+    }
+}
+
+struct Run<'a> {
+    part: Part,
+    input: &'a str,
+}
+
+impl<'a> SolutionFn for Run<'a> {
+    type Output = Result<String, Error>;
+
+    fn call<S: Solution>(self) -> Result<String, Error> {
+        let input = S::parse(self.input)?;
+        match self.part {
+            Part::One => Ok(S::part1(&input)?.to_string()),
+            Part::Two => Ok(S::part2(&input)?.to_string()),
+        }
+    }
+}
+
+pub fn run(day: u32, part: Part, input: &str) -> Result<String, Error> {
+    with_solution(day, Run { part, input })?
+}
+
+fn animate_day<S: Animate>(input: &str, frames: &mut Frames) -> Result<(), Error> {
+    S::animate(&S::parse(input)?, frames)
+}
+
+/// Write frames of the simulation of the day.
+pub fn animate(day: u32, input: &str, frames: &mut Frames) -> Result<(), Error> {
+    match day {
+        11 => animate_day::<day11::Day11>(input, frames),
+        13 => animate_day::<day13::Day13>(input, frames),
+        20 => animate_day::<day20::Day20>(input, frames),
+        25 => animate_day::<day25::Day25>(input, frames),
+        1..=DAYS => Err(Error::NoAnimation(day)),
+        _ => Err(Error::NoSuchDay(day)),
+    }
+}
+
+fn repl_day<S: Simulate>(
+    input: &str,
+    commands: impl BufRead,
+    out: impl Write,
+) -> Result<(), Error> {
+    Repl::<S>::new(S::parse(input)?).run(commands, out)
+}
+
+/// Step through the simulation of the day with commands from `commands`.
+pub fn repl(day: u32, input: &str, commands: impl BufRead, out: impl Write) -> Result<(), Error> {
+    match day {
+        6 => repl_day::<day06::Day06>(input, commands, out),
+        11 => repl_day::<day11::Day11>(input, commands, out),
+        18 => repl_day::<day18::Day18>(input, commands, out),
+        20 => repl_day::<day20::Day20>(input, commands, out),
+        24 => repl_day::<day24::Day24>(input, commands, out),
+        25 => repl_day::<day25::Day25>(input, commands, out),
+        1..=DAYS => Err(Error::NoSimulation(day)),
+        _ => Err(Error::NoSuchDay(day)),
+    }
+}
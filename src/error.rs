@@ -0,0 +1,71 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+use crate::checked::Overflow;
+use crate::parse::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// Failed to read input or write output, with the message of `io::Error`.
+    Io(String),
+    NoSuchDay(u32),
+    NoSuchPart {
+        day: u32,
+        part: u32,
+    },
+    NoAnimation(u32),
+    NoSimulation(u32),
+    /// Input has several valid answers, with the reason.
+    Ambiguous(String),
+    /// Input has no valid answer, with the reason.
+    NoAnswer(String),
+    /// Arithmetic overflow in `--checked` mode.
+    Overflow(Overflow),
+}
+
+impl Error {
+    pub fn with_file(self, file: impl Into<String>) -> Error {
+        match self {
+            Error::Parse(e) => Error::Parse(e.with_file(file)),
+            e => e,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Error {
+        Error::Parse(e)
+    }
+}
+
+impl From<Overflow> for Error {
+    fn from(e: Overflow) -> Error {
+        Error::Overflow(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e.to_string())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::NoSuchDay(day) => write!(f, "no such day: {}", day),
+            Error::NoSuchPart { day, part } => write!(f, "day {} has no part {}", day, part),
+            Error::NoAnimation(day) => write!(f, "day {} has no animation", day),
+            Error::NoSimulation(day) => write!(f, "day {} has no simulation", day),
+            Error::Ambiguous(reason) => write!(f, "ambiguous answer: {}", reason),
+            Error::NoAnswer(reason) => write!(f, "no answer: {}", reason),
+            Error::Overflow(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for Error {}
@@ -0,0 +1,282 @@
+//! Rectangular 2D grid stored row by row.
+
+use std::fmt;
+use std::ops::Index;
+use std::ops::IndexMut;
+
+use crate::parse;
+use crate::parse::Lines;
+use crate::parse::ParseError;
+
+/// `(row, col)`.
+pub type Pos = (usize, usize);
+
+/// What is beyond the grid edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// Nothing, neighbours outside the grid are skipped.
+    Bounded,
+    /// Opposite edge.
+    Wrap,
+}
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Grid<T> {
+        Grid {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Panics if rows are of different length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        assert!(rows.iter().all(|row| row.len() == cols));
+        Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parse one row per line, one cell per char.
+    pub fn from_chars(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Ok(Grid::from_rows(parse::char_grid(input, expected, cell)?))
+    }
+
+    /// Like `from_chars`, but takes the remaining lines of a larger input.
+    pub fn from_char_lines(
+        lines: &mut Lines,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Ok(Grid::from_rows(parse::char_grid_lines(
+            lines, expected, cell,
+        )?))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            Some(&self.cells[row * self.cols + col])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols);
+        self.cells[col..].iter().step_by(self.cols)
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Position `(d_row, d_col)` away from `pos`, `None` if outside of
+    /// bounded grid.
+    pub fn offset(
+        &self,
+        (row, col): Pos,
+        (d_row, d_col): (isize, isize),
+        edges: Edges,
+    ) -> Option<Pos> {
+        match edges {
+            Edges::Bounded => {
+                let row = row.checked_add_signed(d_row)?;
+                let col = col.checked_add_signed(d_col)?;
+                if row < self.rows && col < self.cols {
+                    Some((row, col))
+                } else {
+                    None
+                }
+            }
+            Edges::Wrap => Some((
+                (row as isize + d_row).rem_euclid(self.rows as isize) as usize,
+                (col as isize + d_col).rem_euclid(self.cols as isize) as usize,
+            )),
+        }
+    }
+
+    fn neighbours(
+        &self,
+        pos: Pos,
+        offsets: &'static [(isize, isize)],
+        edges: Edges,
+    ) -> impl Iterator<Item = Pos> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |offset| self.offset(pos, *offset, edges))
+    }
+
+    /// Up, down, left and right.
+    pub fn neighbours4(&self, pos: Pos, edges: Edges) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &OFFSETS_4, edges)
+    }
+
+    /// `neighbours4` and diagonals.
+    pub fn neighbours8(&self, pos: Pos, edges: Edges) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &OFFSETS_8, edges)
+    }
+}
+
+impl<T: From<u8>> Grid<T> {
+    pub fn from_digits(input: &str) -> Result<Grid<T>, ParseError> {
+        Ok(Grid::from_rows(parse::digit_grid(input)?))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &T {
+        assert!(row < self.rows && col < self.cols);
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut T {
+        assert!(row < self.rows && col < self.cols);
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+/// Rows separated by newlines, cells not separated.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.rows {
+            if row != 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::from_digits("123\n456\n").unwrap()
+    }
+
+    #[test]
+    fn views() {
+        let grid = grid();
+        assert_eq!((2, 3), (grid.rows(), grid.cols()));
+        assert_eq!(6, grid[(1, 2)]);
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(vec![&2, &5], grid.col(1).collect::<Vec<_>>());
+        assert_eq!(Some(((1, 0), &4)), grid.iter().nth(3));
+        assert_eq!("123\n456", grid.to_string());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbours4((0, 0), Edges::Bounded).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (1, 0), (0, 2), (0, 1)],
+            grid.neighbours4((0, 0), Edges::Wrap).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)],
+            grid.neighbours8((1, 1), Edges::Bounded).collect::<Vec<_>>()
+        );
+        assert_eq!(8, grid.neighbours8((1, 1), Edges::Wrap).count());
+    }
+
+    #[test]
+    fn from_chars_error() {
+        assert_eq!(
+            ParseError::new(1, 2, "`.` or `#`"),
+            Grid::from_chars(".x", "`.` or `#`", |c| match c {
+                '.' => Some(false),
+                '#' => Some(true),
+                _ => None,
+            })
+            .unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Grid images in binary PGM and PPM formats, and numbered frame
+//! sequences of simulations.
+
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    pub fn gray(v: u8) -> Rgb {
+        Rgb(v, v, v)
+    }
+
+    /// Brightness for grayscale images.
+    fn luma(&self) -> u8 {
+        ((self.0 as u32 * 299 + self.1 as u32 * 587 + self.2 as u32 * 114) / 1000) as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Grayscale.
+    Pgm,
+    Ppm,
+}
+
+impl Format {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Pgm => "pgm",
+            Format::Ppm => "ppm",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "pgm" => Ok(Format::Pgm),
+            "ppm" => Ok(Format::Ppm),
+            _ => Err(format!("invalid image format: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<Rgb>,
+}
+
+impl Image {
+    /// One pixel per cell.
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Image {
+        Image {
+            pixels: grid.map(color),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.cols()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.rows()
+    }
+
+    /// Every pixel becomes a `factor` by `factor` square.
+    pub fn scaled(&self, factor: usize) -> Image {
+        assert!(factor > 0);
+        let mut pixels = Grid::new(self.height() * factor, self.width() * factor, Rgb::BLACK);
+        for pos in pixels.positions() {
+            pixels[pos] = self.pixels[(pos.0 / factor, pos.1 / factor)];
+        }
+        Image { pixels }
+    }
+
+    pub fn write(&self, format: Format, mut w: impl Write) -> io::Result<()> {
+        let magic = match format {
+            Format::Pgm => "P5",
+            Format::Ppm => "P6",
+        };
+        write!(w, "{}\n{} {}\n255\n", magic, self.width(), self.height())?;
+        let mut data = Vec::new();
+        for pixel in self.pixels.values() {
+            match format {
+                Format::Pgm => data.push(pixel.luma()),
+                Format::Ppm => data.extend([pixel.0, pixel.1, pixel.2]),
+            }
+        }
+        w.write_all(&data)
+    }
+
+    pub fn save(&self, format: Format, path: &Path) -> io::Result<()> {
+        let mut w = BufWriter::new(File::create(path)?);
+        self.write(format, &mut w)?;
+        w.flush()
+    }
+}
+
+/// Images written to `frame-0000.ppm`, `frame-0001.ppm` and so on.
+pub struct Frames {
+    dir: PathBuf,
+    format: Format,
+    scale: usize,
+    count: usize,
+}
+
+impl Frames {
+    /// Create `dir` if it does not exist.
+    pub fn new(dir: impl Into<PathBuf>, format: Format, scale: usize) -> io::Result<Frames> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Frames {
+            dir,
+            format,
+            scale,
+            count: 0,
+        })
+    }
+
+    pub fn push(&mut self, image: &Image) -> io::Result<()> {
+        let name = format!("frame-{:04}.{}", self.count, self.format.extension());
+        image
+            .scaled(self.scale)
+            .save(self.format, &self.dir.join(name))?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Number of frames written.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    fn image() -> Image {
+        let grid = Grid::from_rows(vec![vec![false, true]]);
+        Image::from_grid(&grid, |&b| if b { Rgb(255, 0, 0) } else { Rgb::WHITE })
+    }
+
+    #[test]
+    fn ppm() {
+        let mut data = Vec::new();
+        image().write(Format::Ppm, &mut data).unwrap();
+        assert_eq!(b"P6\n2 1\n255\n\xff\xff\xff\xff\x00\x00", &data[..]);
+    }
+
+    #[test]
+    fn pgm() {
+        let mut data = Vec::new();
+        image().scaled(2).write(Format::Pgm, &mut data).unwrap();
+        assert_eq!(b"P5\n4 2\n255\n\xff\xff\x4c\x4c\xff\xff\x4c\x4c", &data[..]);
+    }
+
+    #[test]
+    fn frames() {
+        let dir = env::temp_dir().join(format!("aoc2021-frames-{}", process::id()));
+        let mut frames = Frames::new(&dir, Format::Pgm, 1).unwrap();
+        frames.push(&image()).unwrap();
+        frames.push(&image()).unwrap();
+        assert_eq!(2, frames.count());
+        assert!(dir.join("frame-0001.pgm").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+//! Input files layout.
+//!
+//! Inputs of a day are in `inputs/dayNN/`: the puzzle input is `input.txt`,
+//! examples from the puzzle text are `example.txt` or `example-<name>.txt`.
+//! Other files in the directory, like test fixtures, are not inputs.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const DIR: &str = "inputs";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    Example,
+    Puzzle,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub day: u32,
+    pub kind: Kind,
+    /// `input` or `example-<name>`, file name without `.txt`.
+    pub name: String,
+    /// Relative to the repository root.
+    pub path: String,
+}
+
+pub fn day_dir(day: u32) -> String {
+    format!("{}/day{:02}", DIR, day)
+}
+
+/// Path of the puzzle input of the day.
+pub fn puzzle(day: u32) -> String {
+    format!("{}/input.txt", day_dir(day))
+}
+
+fn kind(name: &str) -> Option<Kind> {
+    if name == "input" {
+        Some(Kind::Puzzle)
+    } else if name == "example" || name.starts_with("example-") {
+        Some(Kind::Example)
+    } else {
+        None
+    }
+}
+
+/// Inputs of the day found in `root`, examples first, sorted by name.
+/// Missing directory means no inputs.
+pub fn discover_day(root: &Path, day: u32) -> io::Result<Vec<Input>> {
+    let dir = day_dir(day);
+    let entries = match fs::read_dir(root.join(&dir)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let file_name = entry?.file_name();
+        let name = match file_name.to_str().and_then(|f| f.strip_suffix(".txt")) {
+            Some(name) => name,
+            None => continue,
+        };
+        if let Some(kind) = kind(name) {
+            inputs.push(Input {
+                day,
+                kind,
+                name: name.to_owned(),
+                path: format!("{}/{}.txt", dir, name),
+            });
+        }
+    }
+    inputs.sort_by(|a, b| (a.kind, &a.name).cmp(&(b.kind, &b.name)));
+    Ok(inputs)
+}
+
+/// Inputs of all days found in `root`.
+pub fn discover(root: &Path) -> io::Result<Vec<Input>> {
+    let mut inputs = Vec::new();
+    for day in 1..=crate::days::DAYS {
+        inputs.extend(discover_day(root, day)?);
+    }
+    Ok(inputs)
+}
+
+/// Path of input given on command line: either a path or a name of
+/// an input of the day, like `example-2`.
+pub fn resolve(root: &Path, day: u32, arg: &str) -> io::Result<String> {
+    if kind(arg).is_some() && !root.join(arg).exists() {
+        if let Some(input) = discover_day(root, day)?.into_iter().find(|i| i.name == arg) {
+            return Ok(input.path);
+        }
+    }
+    Ok(arg.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discover_repo() {
+        let inputs = discover_day(Path::new("."), 12).unwrap();
+        let names: Vec<_> = inputs.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(vec!["example-1", "example-2", "example-3", "input"], names);
+        assert_eq!("inputs/day12/example-2.txt", inputs[1].path);
+        assert_eq!(Kind::Puzzle, inputs[3].kind);
+
+        // Fixture of a unit test.
+        let day19 = discover_day(Path::new("."), 19).unwrap();
+        assert!(day19.iter().all(|i| i.name != "rotations"));
+
+        let all = discover(Path::new(".")).unwrap();
+        assert_eq!(25, all.iter().filter(|i| i.kind == Kind::Puzzle).count());
+    }
+
+    #[test]
+    fn resolve_name() {
+        let root = Path::new(".");
+        assert_eq!(
+            "inputs/day12/example-3.txt",
+            resolve(root, 12, "example-3").unwrap()
+        );
+        assert_eq!("inputs/day01/input.txt", resolve(root, 1, "input").unwrap());
+        assert_eq!("x.txt", resolve(root, 1, "x.txt").unwrap());
+        assert_eq!("-", resolve(root, 1, "-").unwrap());
+    }
+}
@@ -1 +1,75 @@
+use std::fmt;
+use std::io::BufRead;
 
+pub mod all;
+pub mod answers;
+pub mod bench;
+pub mod checked;
+pub mod days;
+mod error;
+pub mod grid;
+pub mod image;
+pub mod inputs;
+pub mod output;
+pub mod parse;
+pub mod repl;
+pub mod search;
+pub mod trace;
+
+pub use error::Error;
+
+pub trait Solution {
+    type Input;
+    type Answer1: fmt::Display;
+    type Answer2: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    /// Parse input from a reader, like stdin or a generated input.
+    fn parse_reader(mut reader: impl BufRead) -> Result<Self::Input, Error> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error>;
+}
+
+/// Solution simulating something on a grid, which can be exported as images.
+pub trait Animate: Solution {
+    /// Push a frame for the initial state and for every step.
+    fn animate(input: &Self::Input, frames: &mut image::Frames) -> Result<(), Error>;
+}
+
+/// Solution which can be stepped one tick at a time in the REPL.
+pub trait Simulate: Solution {
+    type State: Clone + fmt::Display;
+
+    fn start(input: &Self::Input) -> Self::State;
+
+    /// State after one tick, `None` when the simulation is over.
+    fn step(input: &Self::Input, state: &Self::State) -> Result<Option<Self::State>, Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::days::day01::Day01;
+    use crate::parse::ParseError;
+
+    #[test]
+    fn parse_reader() {
+        assert_eq!(vec![1, 2], Day01::parse_reader(&b"1\n2\n"[..]).unwrap());
+        assert_eq!(
+            Error::Parse(ParseError::new(2, 1, "depth")),
+            Day01::parse_reader(Cursor::new("1\nx\n")).unwrap_err()
+        );
+        assert!(matches!(
+            Day01::parse_reader(&b"1\n\xff\n"[..]),
+            Err(Error::Io(_))
+        ));
+    }
+}
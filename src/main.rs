@@ -0,0 +1,496 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::panic;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use aoc2021::all;
+use aoc2021::answers;
+use aoc2021::bench;
+use aoc2021::bench::Baseline;
+use aoc2021::checked;
+use aoc2021::days;
+use aoc2021::days::Part;
+use aoc2021::image;
+use aoc2021::image::Frames;
+use aoc2021::inputs;
+use aoc2021::output;
+use aoc2021::output::PartResult;
+use aoc2021::trace;
+
+const USAGE: &str = "\
+usage: aoc2021 [-v|-vv] [--checked] --day N [--part 1|2] [--input <path>|<name>|-] [--format text|json]
+       aoc2021 [-v|-vv] bench [--day N [--input <path>|<name>]] [--iterations N]
+                     [--baseline <path>] [--save-baseline <path>] [--threshold PERCENT]
+       aoc2021 [-v|-vv] all [--jobs N] [--timeout SECONDS]
+       aoc2021 [-v|-vv] frames --day 11|13|20|25 [--input <path>|<name>|-] --out <dir>
+                     [--scale N] [--image pgm|ppm]
+       aoc2021 [-v|-vv] repl --day 6|11|18|20|24|25 [--input <path>|<name>]
+
+  -v   print solver debug output to stderr
+  -vv  also print per-step state
+  --checked  fail with an error on overflow in sums, products and scores
+             of solvers, and in the day 24 ALU
+
+  --input   input file, or name of a file in inputs/dayNN/ like `example-2`,
+            default is inputs/dayNN/input.txt
+  --format json  print one JSON object per part with day, part, input,
+                 answer, duration_ns and error
+
+`all` checks every input listed in answers.toml, on N threads (default:
+number of CPUs), failing solvers running longer than the timeout (default 60s).
+
+`frames` writes every step of the simulation as an image to <dir>, with
+every cell drawn as N by N pixels (default 4).
+
+`repl` reads commands from stdin to step through the simulation:
+`step [n]`, `back [n]`, `goto N`, `show`, `help` and `quit`.";
+
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    All(AllArgs),
+    Frames(FramesArgs),
+    Repl(ReplArgs),
+}
+
+impl Command {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Command, String> {
+        let mut args = args.peekable();
+        match args.peek().map(String::as_str) {
+            Some("bench") => {
+                args.next();
+                Ok(Command::Bench(BenchArgs::parse(args)?))
+            }
+            Some("all") => {
+                args.next();
+                Ok(Command::All(AllArgs::parse(args)?))
+            }
+            Some("frames") => {
+                args.next();
+                Ok(Command::Frames(FramesArgs::parse(args)?))
+            }
+            Some("repl") => {
+                args.next();
+                Ok(Command::Repl(ReplArgs::parse(args)?))
+            }
+            _ => Ok(Command::Run(RunArgs::parse(args)?)),
+        }
+    }
+}
+
+/// Remove `-v` and `-vv` flags from arguments, return the verbosity.
+fn take_verbosity(args: impl Iterator<Item = String>) -> (u8, Vec<String>) {
+    let mut verbosity = 0;
+    let mut rest = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "-v" => verbosity += 1,
+            "-vv" => verbosity += 2,
+            _ => rest.push(arg),
+        }
+    }
+    (verbosity, rest)
+}
+
+/// Remove `--checked` flag from arguments, return whether it was given.
+fn take_checked(args: Vec<String>) -> (bool, Vec<String>) {
+    let len = args.len();
+    let rest: Vec<String> = args.into_iter().filter(|a| a != "--checked").collect();
+    (rest.len() != len, rest)
+}
+
+fn parse_day(v: &str) -> Result<u32, String> {
+    match v.parse() {
+        Ok(day) if (1..=days::DAYS).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day: {}", v)),
+    }
+}
+
+/// Path of `--input` argument, puzzle input of the day by default.
+fn input_path(day: u32, input: &Option<String>) -> Result<String, String> {
+    match input {
+        Some(arg) => inputs::resolve(Path::new("."), day, arg)
+            .map_err(|e| format!("failed to find input {}: {}", arg, e)),
+        None => Ok(inputs::puzzle(day)),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Just answers, one per line.
+    Text,
+    /// `PartResult` JSON objects, one per line.
+    Json,
+}
+
+impl Format {
+    fn parse(v: &str) -> Result<Format, String> {
+        match v {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format: {}", v)),
+        }
+    }
+}
+
+struct RunArgs {
+    day: u32,
+    parts: Vec<Part>,
+    input: Option<String>,
+    format: Format,
+}
+
+impl RunArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+        let mut day = None;
+        let mut parts = Part::ALL.to_vec();
+        let mut input = None;
+        let mut format = Format::Text;
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+            match arg.as_str() {
+                "--day" => day = Some(parse_day(&value()?)?),
+                "--part" => parts = vec![value()?.parse()?],
+                "--input" => input = Some(value()?),
+                "--format" => format = Format::parse(&value()?)?,
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+        let day = day.ok_or("--day is required")?;
+        Ok(RunArgs {
+            day,
+            parts,
+            input,
+            format,
+        })
+    }
+
+    fn input_path(&self) -> Result<String, String> {
+        input_path(self.day, &self.input)
+    }
+}
+
+struct BenchArgs {
+    /// All days if not specified.
+    day: Option<u32>,
+    input: Option<String>,
+    iterations: u32,
+    baseline: Option<String>,
+    save_baseline: Option<String>,
+    /// Slowdown of median in percent reported as regression.
+    threshold: f64,
+}
+
+impl BenchArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+        let mut bench = BenchArgs {
+            day: None,
+            input: None,
+            iterations: 10,
+            baseline: None,
+            save_baseline: None,
+            threshold: 10.0,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+            match arg.as_str() {
+                "--day" => bench.day = Some(parse_day(&value()?)?),
+                "--input" => bench.input = Some(value()?),
+                "--iterations" => {
+                    let v = value()?;
+                    bench.iterations = match v.parse() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(format!("invalid iterations: {}", v)),
+                    };
+                }
+                "--baseline" => bench.baseline = Some(value()?),
+                "--save-baseline" => bench.save_baseline = Some(value()?),
+                "--threshold" => {
+                    let v = value()?;
+                    bench.threshold = v.parse().map_err(|_| format!("invalid threshold: {}", v))?;
+                }
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+        if bench.input.is_some() && bench.day.is_none() {
+            return Err("--input requires --day".to_owned());
+        }
+        Ok(bench)
+    }
+
+    fn input_path(&self, day: u32) -> Result<String, String> {
+        input_path(day, &self.input)
+    }
+}
+
+struct AllArgs {
+    jobs: usize,
+    timeout: Duration,
+}
+
+impl AllArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<AllArgs, String> {
+        let mut all = AllArgs {
+            jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: Duration::from_secs(60),
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+            match arg.as_str() {
+                "--jobs" => {
+                    let v = value()?;
+                    all.jobs = match v.parse() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(format!("invalid jobs: {}", v)),
+                    };
+                }
+                "--timeout" => {
+                    let v = value()?;
+                    all.timeout = match v.parse() {
+                        Ok(secs) if secs > 0.0 => Duration::from_secs_f64(secs),
+                        _ => return Err(format!("invalid timeout: {}", v)),
+                    };
+                }
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+        Ok(all)
+    }
+}
+
+struct FramesArgs {
+    day: u32,
+    input: Option<String>,
+    out: String,
+    scale: usize,
+    format: image::Format,
+}
+
+impl FramesArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<FramesArgs, String> {
+        let mut day = None;
+        let mut input = None;
+        let mut out = None;
+        let mut scale = 4;
+        let mut format = image::Format::Ppm;
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+            match arg.as_str() {
+                "--day" => day = Some(parse_day(&value()?)?),
+                "--input" => input = Some(value()?),
+                "--out" => out = Some(value()?),
+                "--scale" => {
+                    let v = value()?;
+                    scale = match v.parse() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(format!("invalid scale: {}", v)),
+                    };
+                }
+                "--image" => format = value()?.parse()?,
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+        Ok(FramesArgs {
+            day: day.ok_or("--day is required")?,
+            input,
+            out: out.ok_or("--out is required")?,
+            scale,
+            format,
+        })
+    }
+}
+
+struct ReplArgs {
+    day: u32,
+    input: Option<String>,
+}
+
+impl ReplArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<ReplArgs, String> {
+        let mut day = None;
+        let mut input = None;
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+            match arg.as_str() {
+                "--day" => day = Some(parse_day(&value()?)?),
+                // Stdin is for commands.
+                "--input" => match value()? {
+                    v if v == "-" => return Err("repl cannot read input from stdin".to_owned()),
+                    v => input = Some(v),
+                },
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+        Ok(ReplArgs {
+            day: day.ok_or("--day is required")?,
+            input,
+        })
+    }
+}
+
+fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("failed to read stdin: {}", e))?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path, e))
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    if path == "-" {
+        "<stdin>"
+    } else {
+        path
+    }
+}
+
+fn run_part(day: u32, part: Part, path: &str, input: &Result<String, String>) -> PartResult {
+    let start = Instant::now();
+    let answer = match input {
+        Ok(input) => match panic::catch_unwind(|| days::run(day, part, input)) {
+            Ok(answer) => answer.map_err(|e| e.with_file(file_name(path)).to_string()),
+            Err(payload) => Err(output::panic_message(&*payload)),
+        },
+        Err(e) => Err(e.clone()),
+    };
+    PartResult {
+        day,
+        part,
+        input: file_name(path).to_owned(),
+        answer,
+        duration: start.elapsed(),
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let path = args.input_path()?;
+    let input = read_input(&path);
+    if args.format == Format::Text {
+        input.as_ref().map_err(Clone::clone)?;
+    }
+    let mut failures = 0;
+    for part in &args.parts {
+        let result = run_part(args.day, *part, &path, &input);
+        match args.format {
+            Format::Text => println!("{}", result.answer?),
+            Format::Json => {
+                println!("{}", result.to_json());
+                failures += result.answer.is_err() as usize;
+            }
+        }
+    }
+    if failures != 0 {
+        // Errors are already reported in JSON.
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let text = read_input(path)?;
+            let baseline = Baseline::parse(&text).map_err(|e| e.with_file(path).to_string())?;
+            Some(baseline)
+        }
+        None => None,
+    };
+
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => (1..=days::DAYS).collect(),
+    };
+    let mut results = Vec::new();
+    for day in days {
+        let path = args.input_path(day)?;
+        let input = read_input(&path)?;
+        let result = bench::bench(day, &input, args.iterations)
+            .map_err(|e| e.with_file(file_name(&path)).to_string())?;
+        results.push(result);
+    }
+
+    let (table, regressions) = bench::report(&results, baseline.as_ref(), args.threshold);
+    print!("{}", table);
+
+    if let Some(path) = &args.save_baseline {
+        fs::write(path, Baseline::new(&results).to_string())
+            .map_err(|e| format!("failed to write {}: {}", path, e))?;
+    }
+    if regressions != 0 {
+        return Err(format!(
+            "{} regressions over {}% against baseline",
+            regressions, args.threshold
+        ));
+    }
+    Ok(())
+}
+
+fn all(args: AllArgs) -> Result<(), String> {
+    let text = read_input(answers::FILE)?;
+    let entries = answers::parse(&text).map_err(|e| e.with_file(answers::FILE).to_string())?;
+    let found = inputs::discover(Path::new("."))
+        .map_err(|e| format!("failed to read {}: {}", inputs::DIR, e))?;
+    for input in found {
+        if !entries.iter().any(|e| e.input == input.path) {
+            eprintln!("{} is not listed in {}", input.path, answers::FILE);
+        }
+    }
+    let outcomes = all::run_all(all::jobs(&entries), args.jobs, args.timeout, all::solve);
+    print!("{}", all::report(&outcomes));
+    let counts = all::Counts::new(&outcomes);
+    if counts.fail + counts.timeout != 0 {
+        return Err(format!(
+            "{} of {} answers not passed",
+            counts.fail + counts.timeout,
+            outcomes.len()
+        ));
+    }
+    Ok(())
+}
+
+fn frames(args: FramesArgs) -> Result<(), String> {
+    let path = input_path(args.day, &args.input)?;
+    let input = read_input(&path)?;
+    let mut frames = Frames::new(&args.out, args.format, args.scale)
+        .map_err(|e| format!("failed to create {}: {}", args.out, e))?;
+    days::animate(args.day, &input, &mut frames)
+        .map_err(|e| e.with_file(file_name(&path)).to_string())?;
+    eprintln!("wrote {} frames to {}", frames.count(), args.out);
+    Ok(())
+}
+
+fn repl(args: ReplArgs) -> Result<(), String> {
+    let path = input_path(args.day, &args.input)?;
+    let input = read_input(&path)?;
+    days::repl(args.day, &input, io::stdin().lock(), io::stdout().lock())
+        .map_err(|e| e.with_file(file_name(&path)).to_string())
+}
+
+fn main() {
+    let (verbosity, args) = take_verbosity(env::args().skip(1));
+    trace::set_verbosity(verbosity);
+    let (checked, args) = take_checked(args);
+    checked::set_enabled(checked);
+    let result = match Command::parse(args.into_iter()) {
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::Bench(args)) => bench(args),
+        Ok(Command::All(args)) => all(args),
+        Ok(Command::Frames(args)) => frames(args),
+        Ok(Command::Repl(args)) => repl(args),
+        Err(e) => Err(format!("{}\n{}", e, USAGE)),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
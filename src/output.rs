@@ -0,0 +1,114 @@
+//! Results of the runner in machine-readable form.
+//!
+//! With `--format json` every solved part is printed as one JSON object
+//! per line:
+//!
+//! ```json
+//! {"day":1,"part":1,"input":"inputs/day01/input.txt","answer":"1532","duration_ns":52100,"error":null}
+//! ```
+//!
+//! Answers are always strings because some answers are not numbers.
+//! On failure `answer` is `null` and `error` is the error message.
+
+use std::any::Any;
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::days::Part;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: Part,
+    pub input: String,
+    pub answer: Result<String, String>,
+    /// Parse and solve together.
+    pub duration: Duration,
+}
+
+impl PartResult {
+    pub fn to_json(&self) -> String {
+        let (answer, error) = match &self.answer {
+            Ok(answer) => (json_string(answer), "null".to_owned()),
+            Err(error) => ("null".to_owned(), json_string(error)),
+        };
+        format!(
+            "{{\"day\":{},\"part\":{},\"input\":{},\"answer\":{},\"duration_ns\":{},\"error\":{}}}",
+            self.day,
+            self.part,
+            json_string(&self.input),
+            answer,
+            self.duration.as_nanos(),
+            error
+        )
+    }
+}
+
+/// Message of a caught panic, solvers panic on some unexpected inputs.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = match payload.downcast_ref::<&str>() {
+        Some(s) => s,
+        None => match payload.downcast_ref::<String>() {
+            Some(s) => s.as_str(),
+            None => "unknown",
+        },
+    };
+    format!("panicked: {}", message)
+}
+
+/// Quoted and escaped JSON string.
+pub fn json_string(s: &str) -> String {
+    let mut r = String::with_capacity(s.len() + 2);
+    r.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => r.push_str("\\\""),
+            '\\' => r.push_str("\\\\"),
+            '\n' => r.push_str("\\n"),
+            '\r' => r.push_str("\\r"),
+            '\t' => r.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(r, "\\u{:04x}", c as u32).unwrap(),
+            c => r.push(c),
+        }
+    }
+    r.push('"');
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape() {
+        assert_eq!(r#""a\"b\\c\n\u0001""#, json_string("a\"b\\c\n\u{1}"));
+    }
+
+    #[test]
+    fn panic_messages() {
+        let payload = std::panic::catch_unwind(|| panic!("no win")).unwrap_err();
+        assert_eq!("panicked: no win", panic_message(&*payload));
+        let payload = std::panic::catch_unwind(|| panic!("{} left", 3)).unwrap_err();
+        assert_eq!("panicked: 3 left", panic_message(&*payload));
+    }
+
+    #[test]
+    fn part_result() {
+        let mut result = PartResult {
+            day: 13,
+            part: Part::Two,
+            input: "inputs/day13/input.txt".to_owned(),
+            answer: Ok("#.\n.#".to_owned()),
+            duration: Duration::from_micros(15),
+        };
+        assert_eq!(
+            r##"{"day":13,"part":2,"input":"inputs/day13/input.txt","answer":"#.\n.#","duration_ns":15000,"error":null}"##,
+            result.to_json()
+        );
+        result.answer = Err("x.txt:1:1: expected fold".to_owned());
+        assert_eq!(
+            r##"{"day":13,"part":2,"input":"inputs/day13/input.txt","answer":null,"duration_ns":15000,"error":"x.txt:1:1: expected fold"}"##,
+            result.to_json()
+        );
+    }
+}
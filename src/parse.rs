@@ -0,0 +1,255 @@
+use std::error;
+use std::fmt;
+use std::str;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in chars.
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    pub fn with_file(self, file: impl Into<String>) -> ParseError {
+        ParseError {
+            file: Some(file.into()),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: expected {}",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+            self.expected
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Line of input remembering its position, so errors can point into it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub text: &'a str,
+    /// 1-based.
+    pub number: usize,
+}
+
+impl<'a> Line<'a> {
+    /// `at` must be a substring of `self.text`, otherwise the error points
+    /// to the first column.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let pos = at.as_ptr() as usize;
+        let column = if pos >= start && pos <= start + self.text.len() {
+            self.text[..pos - start].chars().count() + 1
+        } else {
+            1
+        };
+        ParseError::new(self.number, column, expected)
+    }
+
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], expected)
+    }
+
+    pub fn parse<T: FromStr>(&self, at: &str, expected: &str) -> Result<T, ParseError> {
+        at.parse().map_err(|_| self.error(at, expected))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("`{}`", prefix)))
+    }
+
+    pub fn split_once(&self, s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(sep)
+            .ok_or_else(|| self.error_at_end_of(s, format!("`{}`", sep)))
+    }
+
+    pub fn expect(&self, expected: &str) -> Result<(), ParseError> {
+        if self.text == expected {
+            Ok(())
+        } else {
+            Err(self.error(self.text, format!("`{}`", expected)))
+        }
+    }
+
+    fn error_at_end_of(&self, s: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&s[s.len()..], expected)
+    }
+}
+
+pub struct Lines<'a> {
+    lines: str::Lines<'a>,
+    number: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(input: &'a str) -> Lines<'a> {
+        Lines {
+            lines: input.lines(),
+            number: 0,
+        }
+    }
+
+    /// Like `next`, but missing line is an error.
+    pub fn next_line(&mut self, expected: &str) -> Result<Line<'a>, ParseError> {
+        self.next().ok_or_else(|| self.error_after_last(expected))
+    }
+
+    pub fn error_after_last(&self, expected: &str) -> ParseError {
+        ParseError::new(self.number + 1, 1, expected)
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Line<'a>> {
+        let text = self.lines.next()?;
+        self.number += 1;
+        Some(Line {
+            text,
+            number: self.number,
+        })
+    }
+}
+
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines::new(input)
+}
+
+/// Parse comma separated numbers on the single line of input.
+pub fn comma_separated<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError> {
+    let line = lines(input).next_line("numbers")?;
+    let text = line.text.trim_end();
+    text.split(',').map(|s| line.parse(s, "number")).collect()
+}
+
+/// Parse rectangular grid of chars, one row per line.
+pub fn char_grid<T>(
+    input: &str,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    char_grid_lines(&mut lines(input), expected, cell)
+}
+
+/// Like `char_grid`, but takes the remaining lines of a larger input.
+pub fn char_grid_lines<T>(
+    lines: &mut Lines,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for line in &mut *lines {
+        let mut row = Vec::new();
+        for (i, c) in line.text.char_indices() {
+            match cell(c) {
+                Some(v) => row.push(v),
+                None => return Err(line.error(&line.text[i..], expected)),
+            }
+        }
+        if let Some(first) = rows.first() {
+            if first.len() != row.len() {
+                return Err(line.error_at_end(format!("{} cells", first.len())));
+            }
+        }
+        rows.push(row);
+    }
+    if rows.is_empty() || rows[0].is_empty() {
+        return Err(lines.error_after_last(expected));
+    }
+    Ok(rows)
+}
+
+pub fn digit_grid<T: From<u8>>(input: &str) -> Result<Vec<Vec<T>>, ParseError> {
+    char_grid(input, "digit", |c| c.to_digit(10).map(|d| T::from(d as u8)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_column() {
+        let mut lines = lines("a\nfoo,bar");
+        lines.next().unwrap();
+        let line = lines.next().unwrap();
+        let (_, bar) = line.split_once(line.text, ",").unwrap();
+        assert_eq!(
+            ParseError::new(2, 5, "number"),
+            line.parse::<u32>(bar, "number").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn split_once_missing() {
+        let line = lines("foo").next().unwrap();
+        assert_eq!(
+            ParseError::new(1, 4, "`,`"),
+            line.split_once(line.text, ",").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn next_line_eof() {
+        let mut lines = lines("x\n");
+        lines.next_line("x").unwrap();
+        assert_eq!(
+            ParseError::new(2, 1, "y"),
+            lines.next_line("y").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn display() {
+        let e = ParseError::new(3, 7, "`->`").with_file("inputs/day14/input.txt");
+        assert_eq!("inputs/day14/input.txt:3:7: expected `->`", e.to_string());
+    }
+
+    #[test]
+    fn digit_grid_errors() {
+        assert_eq!(
+            vec![vec![1u8, 2], vec![3, 4]],
+            digit_grid("12\n34\n").unwrap()
+        );
+        assert_eq!(
+            ParseError::new(2, 2, "digit"),
+            digit_grid::<u8>("12\n3x\n").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::new(2, 4, "2 cells"),
+            digit_grid::<u8>("12\n345\n").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn comma_separated_error() {
+        assert_eq!(vec![3, 4, 5], comma_separated::<u32>("3,4,5\n").unwrap());
+        assert_eq!(
+            ParseError::new(1, 3, "number"),
+            comma_separated::<u32>("3,x,5").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,324 @@
+//! Interactive stepping through simulations.
+//!
+//! Commands are read line by line:
+//!
+//! - `step [n]`: advance one or `n` ticks
+//! - `back [n]`: return one or `n` ticks, to a state from history
+//! - `goto N`: go to tick `N`, forward or back
+//! - `show`: print the current state
+//! - `help`, `quit`
+
+use std::io::BufRead;
+use std::io::Write;
+
+use crate::Error;
+use crate::Simulate;
+
+const HELP: &str = "\
+step [n]  advance one or n ticks
+back [n]  return one or n ticks
+goto N    go to tick N
+show      print the current state
+quit      exit";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Step(usize),
+    Back(usize),
+    Goto(usize),
+    Show,
+    Help,
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Command, String> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("");
+        let arg = words.next();
+        if let Some(extra) = words.next() {
+            return Err(format!("unexpected argument: {}", extra));
+        }
+        let count = |arg: Option<&str>| match arg {
+            None => Ok(1),
+            Some(n) => n.parse().map_err(|_| format!("invalid count: {}", n)),
+        };
+        let no_arg = |command| match arg {
+            None => Ok(command),
+            Some(arg) => Err(format!("unexpected argument: {}", arg)),
+        };
+        match command {
+            "step" | "s" => Ok(Command::Step(count(arg)?)),
+            "back" | "b" => Ok(Command::Back(count(arg)?)),
+            "goto" | "g" => match arg {
+                Some(tick) => Ok(Command::Goto(
+                    tick.parse()
+                        .map_err(|_| format!("invalid tick: {}", tick))?,
+                )),
+                None => Err("goto requires a tick".to_owned()),
+            },
+            "show" => no_arg(Command::Show),
+            "help" => no_arg(Command::Help),
+            "quit" | "q" => no_arg(Command::Quit),
+            _ => Err(format!("unknown command: {}", command)),
+        }
+    }
+}
+
+/// Simulation with every state seen so far, so going back is free and
+/// going forward again reuses computed states.
+pub struct Repl<S: Simulate> {
+    input: S::Input,
+    /// State at every tick up to the furthest one computed.
+    history: Vec<S::State>,
+    tick: usize,
+    /// The last state in history has no next state.
+    finished: bool,
+}
+
+impl<S: Simulate> Repl<S> {
+    pub fn new(input: S::Input) -> Repl<S> {
+        let start = S::start(&input);
+        Repl {
+            input,
+            history: vec![start],
+            tick: 0,
+            finished: false,
+        }
+    }
+
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    pub fn state(&self) -> &S::State {
+        &self.history[self.tick]
+    }
+
+    /// Whether the current state is the last one.
+    pub fn is_finished(&self) -> bool {
+        self.finished && self.tick + 1 == self.history.len()
+    }
+
+    /// Advance `n` ticks, or less if the simulation is over.
+    pub fn step(&mut self, n: usize) -> Result<(), Error> {
+        for _ in 0..n {
+            if self.tick + 1 == self.history.len() {
+                if self.finished {
+                    break;
+                }
+                match S::step(&self.input, &self.history[self.tick])? {
+                    Some(state) => self.history.push(state),
+                    None => {
+                        self.finished = true;
+                        break;
+                    }
+                }
+            }
+            self.tick += 1;
+        }
+        Ok(())
+    }
+
+    pub fn back(&mut self, n: usize) {
+        self.tick = self.tick.saturating_sub(n);
+    }
+
+    pub fn goto(&mut self, tick: usize) -> Result<(), Error> {
+        if tick <= self.tick {
+            self.back(self.tick - tick);
+            Ok(())
+        } else {
+            self.step(tick - self.tick)
+        }
+    }
+
+    fn write_tick(&self, out: &mut impl Write) -> Result<(), Error> {
+        let finished = if self.is_finished() {
+            " (finished)"
+        } else {
+            ""
+        };
+        writeln!(out, "tick {}{}", self.tick, finished)?;
+        Ok(())
+    }
+
+    /// Execute commands until `quit` or end of `commands`. Invalid commands
+    /// are reported to `out` and skipped, and so are errors of the
+    /// simulation, which stays at the last tick it reached.
+    pub fn run(&mut self, mut commands: impl BufRead, mut out: impl Write) -> Result<(), Error> {
+        self.write_tick(&mut out)?;
+        loop {
+            write!(out, "> ")?;
+            out.flush()?;
+            let mut line = String::new();
+            if commands.read_line(&mut line)? == 0 {
+                writeln!(out)?;
+                return Ok(());
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            match Command::parse(&line) {
+                Ok(Command::Step(n)) => {
+                    if let Err(e) = self.step(n) {
+                        writeln!(out, "{}", e)?;
+                    }
+                    self.write_tick(&mut out)?;
+                }
+                Ok(Command::Back(n)) => {
+                    self.back(n);
+                    self.write_tick(&mut out)?;
+                }
+                Ok(Command::Goto(tick)) => {
+                    if let Err(e) = self.goto(tick) {
+                        writeln!(out, "{}", e)?;
+                    }
+                    self.write_tick(&mut out)?;
+                }
+                Ok(Command::Show) => {
+                    self.write_tick(&mut out)?;
+                    writeln!(out, "{}", self.state())?;
+                }
+                Ok(Command::Help) => writeln!(out, "{}", HELP)?,
+                Ok(Command::Quit) => return Ok(()),
+                Err(e) => writeln!(out, "{}", e)?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+
+    /// Counts from the input number to 3.
+    struct Count;
+
+    impl Solution for Count {
+        type Input = u32;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<u32, Error> {
+            Ok(input.trim().parse().unwrap())
+        }
+
+        fn part1(input: &u32) -> Result<u32, Error> {
+            Ok(*input)
+        }
+
+        fn part2(input: &u32) -> Result<u32, Error> {
+            Ok(*input)
+        }
+    }
+
+    impl Simulate for Count {
+        type State = u32;
+
+        fn start(input: &u32) -> u32 {
+            *input
+        }
+
+        fn step(_input: &u32, state: &u32) -> Result<Option<u32>, Error> {
+            Ok(if *state < 3 { Some(state + 1) } else { None })
+        }
+    }
+
+    /// Counts from the input number, failing to step past 2.
+    struct Fail;
+
+    impl Solution for Fail {
+        type Input = u32;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<u32, Error> {
+            Ok(input.trim().parse().unwrap())
+        }
+
+        fn part1(input: &u32) -> Result<u32, Error> {
+            Ok(*input)
+        }
+
+        fn part2(input: &u32) -> Result<u32, Error> {
+            Ok(*input)
+        }
+    }
+
+    impl Simulate for Fail {
+        type State = u32;
+
+        fn start(input: &u32) -> u32 {
+            *input
+        }
+
+        fn step(_input: &u32, state: &u32) -> Result<Option<u32>, Error> {
+            if *state < 2 {
+                Ok(Some(state + 1))
+            } else {
+                Err(Error::NoAnswer("past 2".to_owned()))
+            }
+        }
+    }
+
+    #[test]
+    fn history() {
+        let mut repl = Repl::<Count>::new(0);
+        repl.step(2).unwrap();
+        assert_eq!((2, 2), (repl.tick(), *repl.state()));
+        repl.back(5);
+        assert_eq!((0, 0), (repl.tick(), *repl.state()));
+        repl.goto(10).unwrap();
+        assert_eq!((3, 3), (repl.tick(), *repl.state()));
+        assert!(repl.is_finished());
+        repl.goto(1).unwrap();
+        assert_eq!((1, 1), (repl.tick(), *repl.state()));
+        assert!(!repl.is_finished());
+    }
+
+    #[test]
+    fn commands() {
+        let commands = "step\n\nshow\nstep 5\nback\ngoto x\njump\nquit\nstep\n";
+        let mut out = Vec::new();
+        Repl::<Count>::new(1)
+            .run(commands.as_bytes(), &mut out)
+            .unwrap();
+        assert_eq!(
+            "tick 0\n\
+             > tick 1\n\
+             > > tick 1\n\
+             2\n\
+             > tick 2 (finished)\n\
+             > tick 1\n\
+             > invalid tick: x\n\
+             > unknown command: jump\n\
+             > ",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn simulation_error() {
+        let commands = "step 5\nstep\nback\ngoto 4\nshow\n";
+        let mut out = Vec::new();
+        Repl::<Fail>::new(0)
+            .run(commands.as_bytes(), &mut out)
+            .unwrap();
+        assert_eq!(
+            "tick 0\n\
+             > no answer: past 2\n\
+             tick 2\n\
+             > no answer: past 2\n\
+             tick 2\n\
+             > tick 1\n\
+             > no answer: past 2\n\
+             tick 2\n\
+             > tick 2\n\
+             2\n\
+             > \n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+}
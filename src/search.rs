@@ -0,0 +1,132 @@
+//! Shortest path search: Dijkstra and A*.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    /// From start to goal, both included.
+    pub states: Vec<S>,
+}
+
+struct Node {
+    cost: u64,
+    parent: Option<usize>,
+}
+
+/// Cheapest path from `start` to any state satisfying `is_goal`.
+///
+/// `successors` returns next states with the cost of moving to them.
+/// `heuristic` must never overestimate the remaining cost to a goal.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    // States are referenced by index in `states` and `nodes`,
+    // so the state type does not need to be `Ord`.
+    let mut states = vec![start.clone()];
+    let mut indices = HashMap::from([(start, 0)]);
+    let mut nodes = vec![Node {
+        cost: 0,
+        parent: None,
+    }];
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&states[0]), 0, 0)));
+
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        if cost > nodes[i].cost {
+            // Found cheaper path to this state after it was queued.
+            continue;
+        }
+        if is_goal(&states[i]) {
+            let mut path = vec![states[i].clone()];
+            let mut node = &nodes[i];
+            while let Some(parent) = node.parent {
+                path.push(states[parent].clone());
+                node = &nodes[parent];
+            }
+            path.reverse();
+            return Some(Path { cost, states: path });
+        }
+
+        for (next, step) in successors(&states[i]) {
+            let next_cost = cost + step;
+            let j = match indices.get(&next) {
+                Some(&j) if next_cost >= nodes[j].cost => continue,
+                Some(&j) => {
+                    nodes[j] = Node {
+                        cost: next_cost,
+                        parent: Some(i),
+                    };
+                    j
+                }
+                None => {
+                    let j = states.len();
+                    indices.insert(next.clone(), j);
+                    states.push(next);
+                    nodes.push(Node {
+                        cost: next_cost,
+                        parent: Some(i),
+                    });
+                    j
+                }
+            };
+            queue.push(Reverse((next_cost + heuristic(&states[j]), next_cost, j)));
+        }
+    }
+    None
+}
+
+/// `astar` without heuristic.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps along the number line: +1 costs 1, *2 costs 2.
+    fn successors(n: &u32) -> Vec<(u32, u64)> {
+        vec![(n + 1, 1), (n * 2, 2)]
+    }
+
+    #[test]
+    fn dijkstra_path() {
+        let path = dijkstra(1, successors, |&n| n == 12).unwrap();
+        assert_eq!(6, path.cost);
+        assert_eq!(vec![1, 2, 3, 6, 12], path.states);
+    }
+
+    #[test]
+    fn astar_same_cost() {
+        let target = 100;
+        let dijkstra = dijkstra(1, successors, |&n| n == target).unwrap();
+        // Any step costs at least 1.
+        let heuristic = |&n: &u32| if n < target { 1 } else { 0 };
+        let astar = astar(1, successors, heuristic, |&n| n == target).unwrap();
+        assert_eq!(dijkstra.cost, astar.cost);
+    }
+
+    #[test]
+    fn unreachable() {
+        assert_eq!(None, dijkstra(5, |&n: &u32| [(n / 2, 1)], |&n| n == 7));
+    }
+}
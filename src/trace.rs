@@ -0,0 +1,38 @@
+//! Debug output of solvers, enabled with `-v` and `-vv` in the runner.
+//!
+//! Use `debug!` for an overview of what solver does (level 1), and
+//! `trace!` for per-step state like grids or search frontiers (level 2).
+//! Output goes to stderr.
+
+use std::sync::atomic::AtomicU8;
+use std::sync::atomic::Ordering;
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn enabled(level: u8) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level
+}
+
+/// `eprintln!` when run with `-v` or more.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled(1) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// `eprintln!` when run with `-vv`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled(2) {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,33 @@
+use std::fs;
+use std::path::Path;
+
+use aoc2021::answers;
+use aoc2021::days;
+use aoc2021::days::Part;
+use aoc2021::inputs;
+
+fn check(day: u32, input: &str, part1: Option<&str>, part2: Option<&str>) {
+    let content = fs::read_to_string(input).unwrap();
+    for (part, expected) in Part::ALL.into_iter().zip([part1, part2]) {
+        if let Some(expected) = expected {
+            let answer = match days::run(day, part, &content) {
+                Ok(answer) => answer,
+                Err(e) => panic!("{}", e.with_file(input)),
+            };
+            assert_eq!(expected, answer, "{} part {}", input, part);
+        }
+    }
+}
+
+#[test]
+fn every_input_listed() {
+    let entries = answers::parse(&fs::read_to_string(answers::FILE).unwrap()).unwrap();
+    for input in inputs::discover(Path::new(".")).unwrap() {
+        let entry = entries.iter().find(|e| e.input == input.path);
+        assert!(entry.is_some(), "{} not in {}", input.path, answers::FILE);
+        assert_eq!(input.day, entry.unwrap().day, "{}", input.path);
+    }
+}
+
+// Tests generated by `build.rs` from `answers.toml`.
+include!(concat!(env!("OUT_DIR"), "/answers.rs"));
@@ -0,0 +1,458 @@
+//! Seeded generators of valid puzzle inputs.
+//!
+//! Each generator returns the structure it generated, which is what
+//! reference implementations work on, and `input()` formats it the way
+//! puzzle inputs are formatted.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Day 4.
+#[derive(Debug)]
+pub struct Bingo {
+    pub numbers: Vec<u32>,
+    pub boards: Vec<[[u32; 5]; 5]>,
+}
+
+impl Bingo {
+    pub fn input(&self) -> String {
+        let numbers: Vec<_> = self.numbers.iter().map(|n| n.to_string()).collect();
+        let mut s = numbers.join(",");
+        s.push('\n');
+        for board in &self.boards {
+            s.push('\n');
+            for row in board {
+                let row: Vec<_> = row.iter().map(|n| format!("{:>2}", n)).collect();
+                writeln!(s, "{}", row.join(" ")).unwrap();
+            }
+        }
+        s
+    }
+}
+
+/// All numbers are drawn, so every board wins eventually.
+pub fn bingo(rng: &mut StdRng) -> Bingo {
+    let count = rng.gen_range(25..100);
+    let mut numbers: Vec<u32> = (0..count).collect();
+    numbers.shuffle(rng);
+    let boards = (0..rng.gen_range(1..10))
+        .map(|_| {
+            let mut cells = numbers.choose_multiple(rng, 25).copied();
+            [(); 5].map(|_| [(); 5].map(|_| cells.next().unwrap()))
+        })
+        .collect();
+    Bingo { numbers, boards }
+}
+
+/// Day 5, `(from, to)` of `(x, y)`.
+#[derive(Debug)]
+pub struct Vents {
+    pub lines: Vec<((i64, i64), (i64, i64))>,
+}
+
+impl Vents {
+    pub fn input(&self) -> String {
+        let mut s = String::new();
+        for ((x1, y1), (x2, y2)) in &self.lines {
+            writeln!(s, "{},{} -> {},{}", x1, y1, x2, y2).unwrap();
+        }
+        s
+    }
+}
+
+/// Horizontal, vertical and 45 degree lines.
+pub fn vents(rng: &mut StdRng) -> Vents {
+    let size: i64 = rng.gen_range(5..40);
+    let lines = (0..rng.gen_range(1..30))
+        .map(|_| {
+            let from = (rng.gen_range(0..size), rng.gen_range(0..size));
+            let dir = match rng.gen_range(0..3) {
+                0 => (rng.gen_range(-1..=1), 0),
+                1 => (0, rng.gen_range(-1..=1)),
+                _ => (
+                    if rng.gen() { 1 } else { -1 },
+                    if rng.gen() { 1 } else { -1 },
+                ),
+            };
+            // Longest length keeping the line inside the square.
+            let room = |p: i64, d: i64| match d {
+                1 => size - 1 - p,
+                -1 => p,
+                _ => size,
+            };
+            let len = rng.gen_range(0..=room(from.0, dir.0).min(room(from.1, dir.1)));
+            (from, (from.0 + dir.0 * len, from.1 + dir.1 * len))
+        })
+        .collect();
+    Vents { lines }
+}
+
+/// Day 12.
+#[derive(Debug)]
+pub struct Caves {
+    pub edges: Vec<(String, String)>,
+}
+
+impl Caves {
+    pub fn input(&self) -> String {
+        let mut s = String::new();
+        for (a, b) in &self.edges {
+            writeln!(s, "{}-{}", a, b).unwrap();
+        }
+        s
+    }
+}
+
+/// Big caves are never connected to each other, otherwise there would
+/// be infinitely many paths.
+pub fn caves(rng: &mut StdRng) -> Caves {
+    let mut names = vec!["start".to_owned(), "end".to_owned()];
+    for i in 0..rng.gen_range(1..5) {
+        names.push(format!(
+            "{}{}",
+            (b'a' + i) as char,
+            (b'a' + rng.gen_range(0..26)) as char
+        ));
+    }
+    for i in 0..rng.gen_range(0..3) {
+        names.push(format!("{}", (b'A' + i) as char));
+    }
+    let is_big = |name: &str| name.bytes().all(|b| b.is_ascii_uppercase());
+
+    let mut pairs = Vec::new();
+    for (i, a) in names.iter().enumerate() {
+        for b in &names[i + 1..] {
+            if !(is_big(a) && is_big(b)) {
+                pairs.push((a.clone(), b.clone()));
+            }
+        }
+    }
+    pairs.shuffle(rng);
+    pairs.truncate(rng.gen_range(1..=pairs.len().min(10)));
+    let mut edges = pairs;
+    // Solver requires start and end to be connected.
+    for name in ["start", "end"] {
+        if !edges.iter().any(|(a, b)| a == name || b == name) {
+            edges.push((name.to_owned(), names[2].clone()));
+        }
+    }
+    for edge in &mut edges {
+        if rng.gen() {
+            *edge = (edge.1.clone(), edge.0.clone());
+        }
+    }
+    Caves { edges }
+}
+
+/// Day 18.
+#[derive(Debug, Clone)]
+pub enum Snailfish {
+    Number(u64),
+    Pair(Box<Snailfish>, Box<Snailfish>),
+}
+
+impl Snailfish {
+    pub fn input(numbers: &[Snailfish]) -> String {
+        let mut s = String::new();
+        for number in numbers {
+            writeln!(s, "{}", number.to_string_rec()).unwrap();
+        }
+        s
+    }
+
+    fn to_string_rec(&self) -> String {
+        match self {
+            Snailfish::Number(n) => n.to_string(),
+            Snailfish::Pair(a, b) => format!("[{},{}]", a.to_string_rec(), b.to_string_rec()),
+        }
+    }
+}
+
+fn snailfish_elem(rng: &mut StdRng, depth: u32) -> Snailfish {
+    if depth < 4 && rng.gen_bool(0.6) {
+        snailfish_pair(rng, depth + 1)
+    } else {
+        Snailfish::Number(rng.gen_range(0..10))
+    }
+}
+
+fn snailfish_pair(rng: &mut StdRng, depth: u32) -> Snailfish {
+    Snailfish::Pair(
+        Box::new(snailfish_elem(rng, depth)),
+        Box::new(snailfish_elem(rng, depth)),
+    )
+}
+
+/// Reduced numbers: nested at most four pairs deep, regular numbers
+/// below 10.
+pub fn snailfish(rng: &mut StdRng) -> Vec<Snailfish> {
+    (0..rng.gen_range(2..8))
+        .map(|_| snailfish_pair(rng, 1))
+        .collect()
+}
+
+/// Day 22.
+#[derive(Debug)]
+pub struct Cuboid {
+    pub on: bool,
+    /// Inclusive ranges of x, y and z.
+    pub ranges: [(i64, i64); 3],
+}
+
+impl Cuboid {
+    pub fn input(cuboids: &[Cuboid]) -> String {
+        let mut s = String::new();
+        for c in cuboids {
+            let [(x1, x2), (y1, y2), (z1, z2)] = c.ranges;
+            writeln!(
+                s,
+                "{} x={}..{},y={}..{},z={}..{}",
+                if c.on { "on" } else { "off" },
+                x1,
+                x2,
+                y1,
+                y2,
+                z1,
+                z2
+            )
+            .unwrap();
+        }
+        s
+    }
+}
+
+/// Coordinates within `-limit..=limit`.
+pub fn cuboids(rng: &mut StdRng, limit: i64) -> Vec<Cuboid> {
+    (0..rng.gen_range(1..12))
+        .map(|_| Cuboid {
+            on: rng.gen_bool(0.7),
+            ranges: [(); 3].map(|_| {
+                let from = rng.gen_range(-limit..=limit);
+                (from, (from + rng.gen_range(0..limit)).min(limit))
+            }),
+        })
+        .collect()
+}
+
+/// Day 16.
+#[derive(Debug)]
+pub enum Packet {
+    Literal {
+        version: u8,
+        value: u64,
+    },
+    Operator {
+        version: u8,
+        type_id: u8,
+        /// Length given as number of sub-packets instead of bits.
+        count_length: bool,
+        subs: Vec<Packet>,
+    },
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: u64, count: u32) {
+    for i in (0..count).rev() {
+        bits.push((value >> i) & 1 != 0);
+    }
+}
+
+impl Packet {
+    fn encode(&self, bits: &mut Vec<bool>) {
+        match self {
+            Packet::Literal { version, value } => {
+                push_bits(bits, *version as u64, 3);
+                push_bits(bits, 4, 3);
+                let groups = (64 - value.leading_zeros()).div_ceil(4).max(1);
+                for i in (0..groups).rev() {
+                    bits.push(i != 0);
+                    push_bits(bits, value >> (i * 4), 4);
+                }
+            }
+            Packet::Operator {
+                version,
+                type_id,
+                count_length,
+                subs,
+            } => {
+                push_bits(bits, *version as u64, 3);
+                push_bits(bits, *type_id as u64, 3);
+                let mut sub_bits = Vec::new();
+                for sub in subs {
+                    sub.encode(&mut sub_bits);
+                }
+                bits.push(*count_length);
+                if *count_length {
+                    push_bits(bits, subs.len() as u64, 11);
+                } else {
+                    push_bits(bits, sub_bits.len() as u64, 15);
+                }
+                bits.extend(sub_bits);
+            }
+        }
+    }
+
+    /// Hex transmission, padded with zeros.
+    pub fn input(&self) -> String {
+        let mut bits = Vec::new();
+        self.encode(&mut bits);
+        while !bits.len().is_multiple_of(4) {
+            bits.push(false);
+        }
+        let mut s = String::new();
+        for digit in bits.chunks(4) {
+            let v = digit.iter().fold(0, |v, &b| (v << 1) | b as u32);
+            s.push(char::from_digit(v, 16).unwrap().to_ascii_uppercase());
+        }
+        s.push('\n');
+        s
+    }
+}
+
+fn packet_at(rng: &mut StdRng, depth: u32) -> Packet {
+    let version = rng.gen_range(0..8);
+    if depth == 0 || rng.gen_bool(0.3) {
+        let bits = rng.gen_range(1..16);
+        return Packet::Literal {
+            version,
+            value: rng.gen_range(0..1 << bits),
+        };
+    }
+    let type_id = [0, 1, 2, 3, 5, 6, 7][rng.gen_range(0..7)];
+    let subs = match type_id {
+        5..=7 => (0..2).map(|_| packet_at(rng, depth - 1)).collect(),
+        // Products of literals only, so that values fit in `u64`.
+        1 => (0..rng.gen_range(1..4))
+            .map(|_| packet_at(rng, 0))
+            .collect(),
+        _ => (0..rng.gen_range(1..4))
+            .map(|_| packet_at(rng, depth - 1))
+            .collect(),
+    };
+    Packet::Operator {
+        version,
+        type_id,
+        count_length: rng.gen(),
+        subs,
+    }
+}
+
+pub fn packet(rng: &mut StdRng) -> Packet {
+    packet_at(rng, 3)
+}
+
+/// Day 19.
+#[derive(Debug)]
+pub struct Scanners {
+    /// Positions in the common frame.
+    pub positions: Vec<[i64; 3]>,
+    /// Beacons seen by any scanner, in the common frame.
+    pub beacons: BTreeSet<[i64; 3]>,
+    /// Beacons as reported by each scanner: relative and rotated.
+    pub reports: Vec<Vec<[i64; 3]>>,
+}
+
+impl Scanners {
+    pub fn input(&self) -> String {
+        let mut s = String::new();
+        for (i, report) in self.reports.iter().enumerate() {
+            if i != 0 {
+                s.push('\n');
+            }
+            writeln!(s, "--- scanner {} ---", i).unwrap();
+            for [x, y, z] in report {
+                writeln!(s, "{},{},{}", x, y, z).unwrap();
+            }
+        }
+        s
+    }
+}
+
+/// The 24 rotation matrices: axis permutations with signs and determinant 1.
+pub fn rotations() -> Vec<[[i64; 3]; 3]> {
+    let mut r = Vec::new();
+    for perm in [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ] {
+        // Odd permutations need odd number of negations.
+        let odd = matches!(perm, [0, 2, 1] | [1, 0, 2] | [2, 1, 0]);
+        for signs in 0..8 {
+            let negations = (signs as u32).count_ones();
+            if (negations % 2 == 1) != odd {
+                continue;
+            }
+            let mut m = [[0; 3]; 3];
+            for row in 0..3 {
+                m[row][perm[row]] = if signs & (1 << row) != 0 { -1 } else { 1 };
+            }
+            r.push(m);
+        }
+    }
+    r
+}
+
+fn rotate(m: &[[i64; 3]; 3], v: [i64; 3]) -> [i64; 3] {
+    [0, 1, 2].map(|row| (0..3).map(|k| m[row][k] * v[k]).sum())
+}
+
+const RANGE: i64 = 1000;
+
+fn sees(position: [i64; 3], beacon: [i64; 3]) -> bool {
+    (0..3).all(|a| (beacon[a] - position[a]).abs() <= RANGE)
+}
+
+/// Chain of scanners where every next scanner shares at least 12 beacons
+/// with the previous one.
+pub fn scanners(rng: &mut StdRng) -> Scanners {
+    let mut positions = vec![[(); 3].map(|_| rng.gen_range(-500..=500))];
+    for _ in 1..rng.gen_range(2..5) {
+        let last: [i64; 3] = *positions.last().unwrap();
+        positions.push(last.map(|c| c + rng.gen_range(-1200..=1200)));
+    }
+
+    let mut beacons = BTreeSet::new();
+    let mut in_box = |rng: &mut StdRng, lo: [i64; 3], hi: [i64; 3], count: usize| {
+        let mut added = 0;
+        while added < count {
+            let b = [0, 1, 2].map(|a| rng.gen_range(lo[a]..=hi[a]));
+            added += beacons.insert(b) as usize;
+        }
+    };
+    for pair in positions.windows(2) {
+        let lo = [0, 1, 2].map(|a| pair[0][a].max(pair[1][a]) - RANGE);
+        let hi = [0, 1, 2].map(|a| pair[0][a].min(pair[1][a]) + RANGE);
+        in_box(rng, lo, hi, 12);
+    }
+    for p in positions.clone() {
+        let count = rng.gen_range(0..6);
+        in_box(rng, p.map(|c| c - RANGE), p.map(|c| c + RANGE), count);
+    }
+
+    let rotations = rotations();
+    let reports = positions
+        .iter()
+        .map(|&p| {
+            let rot = rotations.choose(rng).unwrap();
+            let mut report: Vec<_> = beacons
+                .iter()
+                .filter(|&&b| sees(p, b))
+                .map(|b| rotate(rot, [0, 1, 2].map(|a| b[a] - p[a])))
+                .collect();
+            report.shuffle(rng);
+            report
+        })
+        .collect();
+    Scanners {
+        positions,
+        beacons,
+        reports,
+    }
+}
@@ -0,0 +1,349 @@
+//! Solvers checked against naive reference implementations on generated
+//! inputs.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+
+use aoc2021::days::day04::Day04;
+use aoc2021::days::day05::Day05;
+use aoc2021::days::day12::Day12;
+use aoc2021::days::day16::Day16;
+use aoc2021::days::day18::Day18;
+use aoc2021::days::day19::Day19;
+use aoc2021::days::day22::Day22;
+use aoc2021::Solution;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod generate;
+
+use generate::Cuboid;
+use generate::Packet;
+use generate::Snailfish;
+
+/// Solve generated `input` and compare answers with the reference.
+/// `None` reference answer is not checked.
+fn check<S: Solution>(seed: u64, input: &str, part1: Option<S::Answer1>, part2: Option<S::Answer2>)
+where
+    S::Answer1: PartialEq + fmt::Debug,
+    S::Answer2: PartialEq + fmt::Debug,
+{
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(e) => panic!("seed {}: {}\ninput:\n{}", seed, e, input),
+    };
+    if let Some(expected) = part1 {
+        let actual = S::part1(&parsed).unwrap();
+        assert_eq!(expected, actual, "seed {} part 1, input:\n{}", seed, input);
+    }
+    if let Some(expected) = part2 {
+        let actual = S::part2(&parsed).unwrap();
+        assert_eq!(expected, actual, "seed {} part 2, input:\n{}", seed, input);
+    }
+}
+
+/// Scores of boards in order of winning, `None` if several boards win
+/// on the same number, because then the puzzle has no single answer.
+fn bingo_scores(bingo: &generate::Bingo) -> Option<Vec<u32>> {
+    let mut drawn = HashSet::new();
+    let mut won = vec![false; bingo.boards.len()];
+    let mut scores = Vec::new();
+    for &n in &bingo.numbers {
+        drawn.insert(n);
+        let mut winners = 0;
+        for (board, won) in bingo.boards.iter().zip(&mut won) {
+            let row = board
+                .iter()
+                .any(|row| row.iter().all(|c| drawn.contains(c)));
+            let col = (0..5).any(|j| board.iter().all(|row| drawn.contains(&row[j])));
+            if !*won && (row || col) {
+                *won = true;
+                winners += 1;
+                let unmarked: u32 = board.iter().flatten().filter(|c| !drawn.contains(c)).sum();
+                scores.push(n * unmarked);
+            }
+        }
+        if winners > 1 {
+            return None;
+        }
+    }
+    Some(scores)
+}
+
+#[test]
+fn bingo() {
+    for seed in 0..100 {
+        let bingo = generate::bingo(&mut StdRng::seed_from_u64(seed));
+        if let Some(scores) = bingo_scores(&bingo) {
+            check::<Day04>(
+                seed,
+                &bingo.input(),
+                scores.first().copied(),
+                scores.last().copied(),
+            );
+        }
+    }
+}
+
+/// Number of points covered by at least two lines.
+fn vents_overlaps(vents: &generate::Vents, diagonals: bool) -> usize {
+    let mut covered = HashMap::new();
+    for &((x1, y1), (x2, y2)) in &vents.lines {
+        if !diagonals && x1 != x2 && y1 != y2 {
+            continue;
+        }
+        let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+        let (mut x, mut y) = (x1, y1);
+        loop {
+            *covered.entry((x, y)).or_insert(0) += 1;
+            if (x, y) == (x2, y2) {
+                break;
+            }
+            x += dx;
+            y += dy;
+        }
+    }
+    covered.values().filter(|&&c| c >= 2).count()
+}
+
+#[test]
+fn vents() {
+    for seed in 0..100 {
+        let vents = generate::vents(&mut StdRng::seed_from_u64(seed));
+        check::<Day05>(
+            seed,
+            &vents.input(),
+            Some(vents_overlaps(&vents, false)),
+            Some(vents_overlaps(&vents, true)),
+        );
+    }
+}
+
+/// Paths from `cave` to `end` visiting small caves once, and one small
+/// cave twice if `twice` is allowed.
+fn cave_paths<'a>(
+    edges: &'a [(String, String)],
+    cave: &'a str,
+    visited: &mut Vec<&'a str>,
+    twice: bool,
+) -> u64 {
+    if cave == "end" {
+        return 1;
+    }
+    visited.push(cave);
+    let mut count = 0;
+    for (a, b) in edges {
+        let next = if a == cave {
+            b.as_str()
+        } else if b == cave {
+            a.as_str()
+        } else {
+            continue;
+        };
+        if next == "start" {
+            continue;
+        }
+        let small = next.bytes().all(|b| b.is_ascii_lowercase());
+        if small && visited.contains(&next) {
+            if twice {
+                count += cave_paths(edges, next, visited, false);
+            }
+        } else {
+            count += cave_paths(edges, next, visited, twice);
+        }
+    }
+    visited.pop();
+    count
+}
+
+#[test]
+fn caves() {
+    for seed in 0..100 {
+        let caves = generate::caves(&mut StdRng::seed_from_u64(seed));
+        check::<Day12>(
+            seed,
+            &caves.input(),
+            Some(cave_paths(&caves.edges, "start", &mut Vec::new(), false)),
+            Some(cave_paths(&caves.edges, "start", &mut Vec::new(), true)),
+        );
+    }
+}
+
+fn version_sum(packet: &Packet) -> u32 {
+    match packet {
+        Packet::Literal { version, .. } => *version as u32,
+        Packet::Operator { version, subs, .. } => {
+            *version as u32 + subs.iter().map(version_sum).sum::<u32>()
+        }
+    }
+}
+
+fn eval(packet: &Packet) -> u64 {
+    match packet {
+        Packet::Literal { value, .. } => *value,
+        Packet::Operator { type_id, subs, .. } => {
+            let values: Vec<u64> = subs.iter().map(eval).collect();
+            match type_id {
+                0 => values.iter().sum(),
+                1 => values.iter().product(),
+                2 => *values.iter().min().unwrap(),
+                3 => *values.iter().max().unwrap(),
+                5 => (values[0] > values[1]) as u64,
+                6 => (values[0] < values[1]) as u64,
+                7 => (values[0] == values[1]) as u64,
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+#[test]
+fn bits() {
+    for seed in 0..100 {
+        let packet = generate::packet(&mut StdRng::seed_from_u64(seed));
+        check::<Day16>(
+            seed,
+            &packet.input(),
+            Some(version_sum(&packet)),
+            Some(eval(&packet)),
+        );
+    }
+}
+
+/// Snailfish number as regular numbers with their nesting depth.
+fn flatten(number: &Snailfish, depth: u32, flat: &mut Vec<(u64, u32)>) {
+    match number {
+        Snailfish::Number(n) => flat.push((*n, depth)),
+        Snailfish::Pair(a, b) => {
+            flatten(a, depth + 1, flat);
+            flatten(b, depth + 1, flat);
+        }
+    }
+}
+
+fn snailfish_add(a: &[(u64, u32)], b: &[(u64, u32)]) -> Vec<(u64, u32)> {
+    let mut sum: Vec<_> = a.iter().chain(b).map(|&(n, d)| (n, d + 1)).collect();
+    loop {
+        if let Some(i) = sum.iter().position(|&(_, d)| d > 4) {
+            let ((l, d), (r, _)) = (sum[i], sum[i + 1]);
+            if i > 0 {
+                sum[i - 1].0 += l;
+            }
+            if i + 2 < sum.len() {
+                sum[i + 2].0 += r;
+            }
+            sum.splice(i..i + 2, [(0, d - 1)]);
+        } else if let Some(i) = sum.iter().position(|&(n, _)| n >= 10) {
+            let (n, d) = sum[i];
+            sum.splice(i..i + 1, [(n / 2, d + 1), (n.div_ceil(2), d + 1)]);
+        } else {
+            return sum;
+        }
+    }
+}
+
+fn magnitude(number: &[(u64, u32)]) -> u64 {
+    let mut number = number.to_vec();
+    while number.len() > 1 {
+        // Deepest pair is two regular numbers.
+        let depth = number.iter().map(|&(_, d)| d).max().unwrap();
+        let i = number.iter().position(|&(_, d)| d == depth).unwrap();
+        let (l, r) = (number[i].0, number[i + 1].0);
+        number.splice(i..i + 2, [(3 * l + 2 * r, depth - 1)]);
+    }
+    number[0].0
+}
+
+#[test]
+fn snailfish() {
+    for seed in 0..100 {
+        let numbers = generate::snailfish(&mut StdRng::seed_from_u64(seed));
+        let flat: Vec<_> = numbers
+            .iter()
+            .map(|n| {
+                let mut flat = Vec::new();
+                flatten(n, 0, &mut flat);
+                flat
+            })
+            .collect();
+        let sum = flat[1..]
+            .iter()
+            .fold(flat[0].clone(), |sum, n| snailfish_add(&sum, n));
+        let mut largest = 0;
+        for (i, a) in flat.iter().enumerate() {
+            for (j, b) in flat.iter().enumerate() {
+                if i != j {
+                    largest = largest.max(magnitude(&snailfish_add(a, b)));
+                }
+            }
+        }
+        check::<Day18>(
+            seed,
+            &Snailfish::input(&numbers),
+            Some(magnitude(&sum)),
+            Some(largest),
+        );
+    }
+}
+
+/// Cubes turned on within `-50..=50` and in total, cube by cube.
+fn reactor_cubes(cuboids: &[Cuboid], limit: i64) -> (u64, u64) {
+    let size = (2 * limit + 1) as usize;
+    let index = |c: i64| (c + limit) as usize;
+    let mut on = vec![false; size * size * size];
+    for cuboid in cuboids {
+        let [(x1, x2), (y1, y2), (z1, z2)] = cuboid.ranges;
+        for x in x1..=x2 {
+            for y in y1..=y2 {
+                for z in z1..=z2 {
+                    on[(index(x) * size + index(y)) * size + index(z)] = cuboid.on;
+                }
+            }
+        }
+    }
+    let mut init = 0;
+    let mut all = 0;
+    for x in -limit..=limit {
+        for y in -limit..=limit {
+            for z in -limit..=limit {
+                if on[(index(x) * size + index(y)) * size + index(z)] {
+                    all += 1;
+                    if [x, y, z].iter().all(|c| c.abs() <= 50) {
+                        init += 1;
+                    }
+                }
+            }
+        }
+    }
+    (init, all)
+}
+
+#[test]
+fn cuboids() {
+    for seed in 0..20 {
+        let cuboids = generate::cuboids(&mut StdRng::seed_from_u64(seed), 60);
+        let (init, all) = reactor_cubes(&cuboids, 60);
+        check::<Day22>(seed, &Cuboid::input(&cuboids), Some(init), Some(all));
+    }
+}
+
+#[test]
+fn scanners() {
+    // Solver is slow in debug build, so only a few small inputs.
+    for seed in 0..4 {
+        let scanners = generate::scanners(&mut StdRng::seed_from_u64(seed));
+        let mut farthest = 0;
+        for a in &scanners.positions {
+            for b in &scanners.positions {
+                farthest = farthest.max((0..3).map(|i| (a[i] - b[i]).abs()).sum());
+            }
+        }
+        check::<Day19>(
+            seed,
+            &scanners.input(),
+            Some(scanners.beacons.len()),
+            Some(farthest),
+        );
+    }
+}